@@ -0,0 +1,363 @@
+//! Typed flags for the bitfield registers.
+//!
+//! Registers like [`Register::HardwareErrorStatus`] and [`Register::Shutdown`] hold a set of flags rather than a number,
+//! and the meaning of each bit depends on the model. [`Bitfield::decode`] picks the flags for a [`ModelGroup`],
+//! and each flag struct can be encoded back to the raw value of the register.
+//!
+//! ## Example
+//! ```rust
+//! use dynamixel_ct::bitfield::{Bitfield, HardwareError};
+//! use dynamixel_ct::{ModelGroup, Register};
+//!
+//! let error = Bitfield::decode(ModelGroup::XM430, Register::HardwareErrorStatus, 0x30).unwrap();
+//! assert_eq!(error.to_string(), "Electrical Shock Error, Overload Error");
+//!
+//! let shutdown = HardwareError::OVERHEATING | HardwareError::OVERLOAD;
+//! assert_eq!(shutdown.bits(), 0x24);
+//! ```
+
+use core::fmt::{Display, Formatter};
+use core::ops::{BitOr, BitOrAssign};
+
+use dynamixel_registers::models::ModelGroup;
+use dynamixel_registers::{Register, Value};
+
+/// Define a struct of flags, each with the bit it is stored in and the name used by [`Display`]
+macro_rules! bitfield {
+    ($(#[$meta:meta])* $name:ident { $($flag:ident = $bit:literal, $label:literal;)+ }) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $name(u8);
+
+        impl $name {
+            $(
+                #[doc = concat!("Bit ", stringify!($bit), ", ", $label)]
+                pub const $flag: Self = Self(1 << $bit);
+            )+
+
+            const FLAGS: &'static [(Self, &'static str)] = &[$((Self::$flag, $label),)+];
+
+            /// Decode the raw value of the register. Bits that are not flags are kept.
+            pub const fn from_bits(bits: u8) -> Self {
+                Self(bits)
+            }
+
+            /// Encode the flags as the raw value of the register
+            pub const fn bits(&self) -> u8 {
+                self.0
+            }
+
+            /// No flags set
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            /// Check if no flags are set
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            /// Check if all the flags of `other` are set
+            pub const fn contains(&self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Set the flags of `other`
+            pub fn insert(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+
+            /// Clear the flags of `other`
+            pub fn remove(&mut self, other: Self) {
+                self.0 &= !other.0;
+            }
+
+            /// Iterate over the flags set and their names
+            pub fn iter(&self) -> impl Iterator<Item = (Self, &'static str)> + '_ {
+                Self::FLAGS
+                    .iter()
+                    .copied()
+                    .filter(|(flag, _)| self.contains(*flag))
+            }
+        }
+
+        impl BitOr for $name {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl BitOrAssign for $name {
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.insert(rhs);
+            }
+        }
+
+        impl From<u8> for $name {
+            fn from(bits: u8) -> Self {
+                Self::from_bits(bits)
+            }
+        }
+
+        impl From<$name> for u8 {
+            fn from(flags: $name) -> Self {
+                flags.bits()
+            }
+        }
+
+        impl From<$name> for Value {
+            fn from(flags: $name) -> Self {
+                Value::U8(flags.bits())
+            }
+        }
+
+        impl Display for $name {
+            /// The names of the flags set, separated by commas, or `None`
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                write_names(f, self.iter().map(|(_, name)| name))
+            }
+        }
+    };
+}
+
+bitfield! {
+    /// [`Register::HardwareErrorStatus`] and [`Register::Shutdown`] of the protocol 2.0 X and P series and grippers
+    HardwareError {
+        INPUT_VOLTAGE = 0, "Input Voltage Error";
+        OVERHEATING = 2, "Overheating Error";
+        MOTOR_ENCODER = 3, "Motor Encoder Error";
+        ELECTRICAL_SHOCK = 4, "Electrical Shock Error";
+        OVERLOAD = 5, "Overload Error";
+    }
+}
+
+bitfield! {
+    /// [`Register::HardwareErrorStatus`] and [`Register::Shutdown`] of the XL-320
+    Xl320Error {
+        OVERLOAD = 0, "Overload Error";
+        OVERHEATING = 1, "Overheating Error";
+        INPUT_VOLTAGE = 2, "Input Voltage Error";
+    }
+}
+
+bitfield! {
+    /// [`Register::AlarmLed`] and [`Register::Shutdown`] of the protocol 1.0 AX, RX, EX and MX series
+    Protocol1Error {
+        INPUT_VOLTAGE = 0, "Input Voltage Error";
+        ANGLE_LIMIT = 1, "Angle Limit Error";
+        OVERHEATING = 2, "Overheating Error";
+        RANGE = 3, "Range Error";
+        CHECKSUM = 4, "Checksum Error";
+        OVERLOAD = 5, "Overload Error";
+        INSTRUCTION = 6, "Instruction Error";
+    }
+}
+
+bitfield! {
+    /// [`Register::DriveMode`] of the protocol 2.0 X, P and Y series
+    DriveMode {
+        REVERSE = 0, "Reverse Mode";
+        TIME_BASED_PROFILE = 2, "Time-based Profile";
+        TORQUE_ON_BY_GOAL_UPDATE = 3, "Torque On by Goal Update";
+    }
+}
+
+bitfield! {
+    /// [`Register::DriveMode`] of the protocol 1.0 EX-106 and MX-106, which can drive a joint with a second servo
+    Protocol1DriveMode {
+        REVERSE = 0, "Reverse Mode";
+        SLAVE = 1, "Slave Mode";
+    }
+}
+
+bitfield! {
+    /// [`Register::MovingStatus`] of the X, P and Y series.
+    /// Bits 4 and 5 are the type of velocity profile, see [`MovingStatus::velocity_profile`].
+    MovingStatus {
+        IN_POSITION = 0, "In-Position";
+        PROFILE_ONGOING = 1, "Profile Ongoing";
+        FOLLOWING_ERROR = 3, "Following Error";
+    }
+}
+
+bitfield! {
+    /// [`Register::StartupConfiguration`] of the X, P and Y series
+    StartupConfiguration {
+        TORQUE_ON = 0, "Torque On";
+        RAM_RESTORE = 1, "RAM Restore";
+    }
+}
+
+/// The type of velocity profile used to reach the Goal Position, from bits 4 and 5 of [`Register::MovingStatus`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, derive_more::Display)]
+pub enum VelocityProfile {
+    /// No profile, the Goal Position is reached as fast as possible
+    #[display("Step Profile")]
+    Step,
+    /// A constant velocity
+    #[display("Rectangular Profile")]
+    Rectangular,
+    /// Accelerates then decelerates without reaching the Profile Velocity
+    #[display("Triangular Profile")]
+    Triangular,
+    /// Accelerates, holds the Profile Velocity, then decelerates
+    #[display("Trapezoidal Profile")]
+    Trapezoidal,
+}
+
+impl MovingStatus {
+    /// The type of velocity profile
+    pub const fn velocity_profile(&self) -> VelocityProfile {
+        match (self.0 >> 4) & 0b11 {
+            0 => VelocityProfile::Step,
+            1 => VelocityProfile::Rectangular,
+            2 => VelocityProfile::Triangular,
+            _ => VelocityProfile::Trapezoidal,
+        }
+    }
+}
+
+/// The flags of a bitfield register, with the meaning of each bit for the model
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, derive_more::Display, derive_more::From)]
+pub enum Bitfield {
+    /// See [`HardwareError`]
+    HardwareError(HardwareError),
+    /// See [`Xl320Error`]
+    Xl320Error(Xl320Error),
+    /// See [`Protocol1Error`]
+    Protocol1Error(Protocol1Error),
+    /// See [`DriveMode`]
+    DriveMode(DriveMode),
+    /// See [`Protocol1DriveMode`]
+    Protocol1DriveMode(Protocol1DriveMode),
+    /// See [`MovingStatus`]
+    MovingStatus(MovingStatus),
+    /// See [`StartupConfiguration`]
+    StartupConfiguration(StartupConfiguration),
+}
+
+impl Bitfield {
+    /// Decode the raw value of a register of a model group.
+    /// Returns `None` if the register is not a bitfield on the model group.
+    pub fn decode(model_group: ModelGroup, register: Register, bits: u8) -> Option<Self> {
+        use dynamixel_registers::models::Protocol;
+        let protocol = model_group.protocol();
+        let bitfield = match register {
+            Register::HardwareErrorStatus | Register::Shutdown
+                if model_group == ModelGroup::XL320 =>
+            {
+                Xl320Error(bits).into()
+            }
+            Register::AlarmLed | Register::Shutdown if protocol == Protocol::V1 => {
+                Protocol1Error(bits).into()
+            }
+            Register::HardwareErrorStatus | Register::Shutdown => HardwareError(bits).into(),
+            Register::DriveMode if protocol == Protocol::V1 => Protocol1DriveMode(bits).into(),
+            Register::DriveMode => DriveMode(bits).into(),
+            Register::MovingStatus => MovingStatus(bits).into(),
+            Register::StartupConfiguration => StartupConfiguration(bits).into(),
+            _ => return None,
+        };
+        Some(bitfield)
+    }
+
+    /// Encode the flags as the raw value of the register
+    pub const fn bits(&self) -> u8 {
+        match self {
+            Bitfield::HardwareError(flags) => flags.bits(),
+            Bitfield::Xl320Error(flags) => flags.bits(),
+            Bitfield::Protocol1Error(flags) => flags.bits(),
+            Bitfield::DriveMode(flags) => flags.bits(),
+            Bitfield::Protocol1DriveMode(flags) => flags.bits(),
+            Bitfield::MovingStatus(flags) => flags.bits(),
+            Bitfield::StartupConfiguration(flags) => flags.bits(),
+        }
+    }
+}
+
+impl From<Bitfield> for Value {
+    fn from(bitfield: Bitfield) -> Self {
+        Value::U8(bitfield.bits())
+    }
+}
+
+fn write_names<'a>(
+    f: &mut Formatter<'_>,
+    mut names: impl Iterator<Item = &'a str>,
+) -> core::fmt::Result {
+    let Some(first) = names.next() else {
+        return f.write_str("None");
+    };
+    f.write_str(first)?;
+    names.try_for_each(|name| write!(f, ", {}", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        let error = Bitfield::decode(ModelGroup::PH54, Register::HardwareErrorStatus, 0x21);
+        assert_eq!(
+            error,
+            Some(Bitfield::HardwareError(
+                HardwareError::INPUT_VOLTAGE | HardwareError::OVERLOAD
+            ))
+        );
+        // the same bits mean something else on the XL-320 and protocol 1.0 servos
+        assert_eq!(
+            Bitfield::decode(ModelGroup::XL320, Register::Shutdown, 0x01),
+            Some(Bitfield::Xl320Error(Xl320Error::OVERLOAD))
+        );
+        assert_eq!(
+            Bitfield::decode(ModelGroup::MX28, Register::AlarmLed, 0x24)
+                .unwrap()
+                .to_string(),
+            "Overheating Error, Overload Error"
+        );
+        assert_eq!(
+            Bitfield::decode(ModelGroup::MX106, Register::DriveMode, 0x02),
+            Some(Bitfield::Protocol1DriveMode(Protocol1DriveMode::SLAVE))
+        );
+        // the protocol 2.0 series share the same Drive Mode bits
+        for model_group in [ModelGroup::XM430, ModelGroup::PH54, ModelGroup::YM080] {
+            assert_eq!(
+                Bitfield::decode(model_group, Register::DriveMode, 0x01),
+                Some(Bitfield::DriveMode(DriveMode::REVERSE))
+            );
+        }
+        assert_eq!(
+            Bitfield::decode(ModelGroup::XM430, Register::GoalPosition, 0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_encode() {
+        let mut mode = DriveMode::REVERSE;
+        mode |= DriveMode::TIME_BASED_PROFILE;
+        assert_eq!(mode.bits(), 0x05);
+        assert_eq!(Value::from(mode), Value::U8(0x05));
+        mode.remove(DriveMode::REVERSE);
+        assert_eq!(u8::from(mode), 0x04);
+        assert!(StartupConfiguration::from(0x03).contains(StartupConfiguration::RAM_RESTORE));
+        assert_eq!(HardwareError::empty().to_string(), "None");
+    }
+
+    #[test]
+    fn test_moving_status() {
+        let status = MovingStatus::from_bits(0x33);
+        assert_eq!(status.to_string(), "In-Position, Profile Ongoing");
+        assert_eq!(status.velocity_profile(), VelocityProfile::Trapezoidal);
+        // the profile bits are kept when encoding
+        assert_eq!(status.bits(), 0x33);
+        assert!(MovingStatus::empty().is_empty());
+        assert_eq!(
+            MovingStatus::from_bits(0x10).velocity_profile(),
+            VelocityProfile::Rectangular
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! The hardware features of a servo, e.g. to hide the controls of a user interface the servo doesn't have.
+//!
+//! Most capabilities are derived from the registers of the control table, so a [`ControlTable`](crate::ControlTable)
+//! created with a firmware version only reports the features that firmware has.
+//!
+//! ## Example
+//! ```rust
+//! use dynamixel_ct::{ControlTable, ModelGroup, ModelTable, OperatingMode};
+//!
+//! let capabilities = ControlTable::new(ModelGroup::XL430).capabilities();
+//! assert!(!capabilities.current_sensing);
+//! assert!(!capabilities.supports(OperatingMode::Current));
+//! assert!(ControlTable::new(ModelGroup::XM540).capabilities().external_ports > 0);
+//! ```
+
+use dynamixel_registers::models::{ModelGroup, Protocol};
+use dynamixel_registers::{OperatingMode, Register};
+
+use crate::model_table::ModelTable;
+
+/// The External Port Mode registers, one per external port
+const EXTERNAL_PORTS: [Register; 4] = [
+    Register::ExternalPortMode1,
+    Register::ExternalPortMode2,
+    Register::ExternalPortMode3,
+    Register::ExternalPortMode4,
+];
+
+/// The hardware features of a servo, returned by [`ModelTable::capabilities`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Capabilities {
+    /// The version of the protocol the servo speaks
+    pub protocol: Protocol,
+    /// The number of axes, `2` for a dual-axis servo (e.g. 2XL430)
+    pub axes: u8,
+    /// The servo is a gripper, see [`ModelGroup::is_gripper`]
+    pub gripper: bool,
+    /// The servo measures its current ([`Register::PresentCurrent`], or [`Register::Current`] on protocol 1.0).
+    /// Servos without it only report [`Register::PresentLoad`].
+    pub current_sensing: bool,
+    /// The LED can be set to a color, with [`Register::LedRed`] or the color value of [`Register::Led`] on the XL320
+    pub rgb_led: bool,
+    /// The number of external ports, see [`Register::ExternalPortMode1`]
+    pub external_ports: u8,
+    /// The servo has a brake, see [`Register::BrakeDelay`]
+    pub brake: bool,
+    /// The servo has Indirect Address / Indirect Data blocks, see [`ModelTable::indirect_blocks`]
+    pub indirect: bool,
+    /// The operating modes supported by the servo, see [`ModelGroup::operating_modes`]
+    pub operating_modes: &'static [OperatingMode],
+}
+
+impl Capabilities {
+    /// Get the capabilities of a model table
+    pub fn of<T: ModelTable + ?Sized>(table: &T) -> Self {
+        let model_group = table.model_or_group().model_group();
+        let has = |register| table.get(register).is_ok();
+        Capabilities {
+            protocol: model_group.protocol(),
+            axes: model_group.axes(),
+            gripper: model_group.is_gripper(),
+            current_sensing: has(Register::PresentCurrent) || has(Register::Current),
+            rgb_led: has(Register::LedRed) || model_group == ModelGroup::XL320,
+            external_ports: EXTERNAL_PORTS.into_iter().filter(|r| has(*r)).count() as u8,
+            brake: has(Register::BrakeDelay),
+            indirect: !table.indirect_blocks().is_empty(),
+            operating_modes: model_group.operating_modes(),
+        }
+    }
+
+    /// Check if the servo has more than one axis
+    pub const fn is_dual_axis(&self) -> bool {
+        self.axes > 1
+    }
+
+    /// Check if the servo supports an operating mode
+    pub fn supports(&self, mode: OperatingMode) -> bool {
+        self.operating_modes.contains(&mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RHP12RN, XM430, YM080};
+    use crate::ControlTable;
+
+    #[test]
+    fn test_capabilities() {
+        let xm430 = XM430.capabilities();
+        assert!(xm430.current_sensing);
+        assert!(!xm430.rgb_led);
+        assert_eq!(xm430.external_ports, 0);
+        assert!(xm430.indirect);
+        assert!(xm430.supports(OperatingMode::CurrentBasedPosition));
+        assert_eq!(ControlTable::new(ModelGroup::XM430).capabilities(), xm430);
+
+        assert!(YM080.capabilities().brake);
+        assert!(YM080.capabilities().indirect);
+        assert!(RHP12RN.capabilities().gripper);
+        assert_eq!(RHP12RN.capabilities().external_ports, 4);
+        assert!(ControlTable::new(ModelGroup::PH54).capabilities().rgb_led);
+        assert!(ControlTable::new(ModelGroup::XL320).capabilities().rgb_led);
+        assert!(
+            ControlTable::new(ModelGroup::MX64)
+                .capabilities()
+                .current_sensing
+        );
+        assert!(
+            !ControlTable::new(ModelGroup::MX28)
+                .capabilities()
+                .current_sensing
+        );
+
+        let dual = ControlTable::new(ModelGroup::_2XL430).capabilities();
+        assert!(dual.is_dual_axis());
+        assert!(!dual.current_sensing);
+        assert_eq!(dual.protocol, Protocol::V2);
+    }
+}
@@ -3,22 +3,146 @@
 //! The control table is statically allocated to reduce memory usage.
 //!
 use derive_more::{Display, Error};
-use dynamixel_registers::models::{Model, ModelGroup, ModelOrModelGroup};
-use dynamixel_registers::Register;
+use dynamixel_registers::models::{Model, ModelGroup, ModelOrModelGroup, Protocol};
 use dynamixel_registers::RegisterData;
+use dynamixel_registers::{OperatingMode, Register};
+use dynamixel_registers::{Unit, Value, ValueRange};
+
+use crate::indirect::IndirectBlock;
+use crate::model_macro::RegisterTable;
+use crate::ModelTable;
 
 #[derive(Clone, Debug, Display, Error, PartialEq, PartialOrd)]
-#[display("{} does not have {} register", model_or_group, register)]
-/// The `ControlTable` doesn't contain the `Register` requested
-pub struct RegisterError {
-    model_or_group: ModelOrModelGroup,
-    register: Register,
+/// The `Register` requested cannot be used with this `ControlTable`
+pub enum RegisterError {
+    /// The `ControlTable` doesn't contain the `Register` requested
+    #[display("{} does not have {} register", model_or_group, register)]
+    Missing {
+        /// The model or model group of the `ControlTable`
+        model_or_group: ModelOrModelGroup,
+        /// The register requested
+        register: Register,
+    },
+    /// The `Register` requested for writing is read only
+    #[display("{} register is read only on {}", register, model_or_group)]
+    ReadOnly {
+        /// The model or model group of the `ControlTable`
+        model_or_group: ModelOrModelGroup,
+        /// The register requested
+        register: Register,
+    },
+    /// The value is outside the range of valid values of the `Register`
+    #[display(
+        "{} is out of range for {} register on {}, expected {} ~ {}",
+        value,
+        register,
+        model_or_group,
+        min,
+        max
+    )]
+    OutOfRange {
+        /// The model or model group of the `ControlTable`
+        model_or_group: ModelOrModelGroup,
+        /// The register requested
+        register: Register,
+        /// The value that is out of range
+        value: i64,
+        /// The smallest valid value
+        min: i64,
+        /// The largest valid value
+        max: i64,
+    },
+    /// The `Register` requested for writing is in the EEPROM area and torque is enabled
+    #[display(
+        "{} register is in the EEPROM area and is locked while torque is enabled on {}",
+        register,
+        model_or_group
+    )]
+    EepromLocked {
+        /// The model or model group of the `ControlTable`
+        model_or_group: ModelOrModelGroup,
+        /// The register requested
+        register: Register,
+    },
+    /// The `Register` requested was added in a later firmware version than the servo's
+    #[display(
+        "{} register requires firmware version {} but {} has version {}",
+        register,
+        required,
+        model_or_group,
+        firmware
+    )]
+    Firmware {
+        /// The model or model group of the `ControlTable`
+        model_or_group: ModelOrModelGroup,
+        /// The register requested
+        register: Register,
+        /// The firmware version the register was added in
+        required: u8,
+        /// The firmware version of the `ControlTable`
+        firmware: u8,
+    },
+    /// The operating mode is not supported by the model
+    #[display("{} is not supported by {}", mode, model_or_group)]
+    UnsupportedMode {
+        /// The model or model group of the `ControlTable`
+        model_or_group: ModelOrModelGroup,
+        /// The operating mode requested
+        mode: OperatingMode,
+    },
+    /// The value read from the `Register` is not one of its documented values
+    #[display(
+        "{:#04X} is not a known {} value on {}",
+        value,
+        register,
+        model_or_group
+    )]
+    UnknownValue {
+        /// The model or model group of the `ControlTable`
+        model_or_group: ModelOrModelGroup,
+        /// The register the value was read from
+        register: Register,
+        /// The value read
+        value: u8,
+    },
+    /// The model speaks a protocol the packets can't be built for
+    #[display(
+        "{} uses protocol {}, only protocol 2.0 is supported",
+        model_or_group,
+        protocol
+    )]
+    UnsupportedProtocol {
+        /// The model or model group of the `ControlTable`
+        model_or_group: ModelOrModelGroup,
+        /// The protocol of the model
+        protocol: Protocol,
+    },
+    /// The bytes decoded don't cover the `Register`
+    #[display(
+        "{} register is not within the bytes read from {}",
+        register,
+        model_or_group
+    )]
+    NotRead {
+        /// The model or model group of the `ControlTable`
+        model_or_group: ModelOrModelGroup,
+        /// The register missing from the bytes
+        register: Register,
+    },
+    /// The `Register` requested has no physical unit, or the unit differs between the models of the `ModelGroup`
+    #[display("{} register has no unit on {}", register, model_or_group)]
+    NoUnit {
+        /// The model or model group of the `ControlTable`
+        model_or_group: ModelOrModelGroup,
+        /// The register requested
+        register: Register,
+    },
 }
 
 impl RegisterError {
     fn new(model: Option<Model>, model_group: ModelGroup, register: Register) -> Self {
         let model_or_group = model.map_or(model_group.into(), Into::into);
-        Self {
+        Self::Missing {
             model_or_group,
             register,
         }
@@ -27,12 +151,15 @@ impl RegisterError {
 
 /// A control table for a specific model.
 /// The table is statically allocated to reduce memory usage.
-#[derive(PartialEq, Eq, Clone, derive_more::Debug)]
+///
+/// Validation, unit conversion and address lookups are provided by the [`ModelTable`] trait.
+#[derive(PartialEq, Eq, Clone, Copy, derive_more::Debug)]
 pub struct ControlTable {
     model: Option<Model>,
     model_group: ModelGroup,
+    firmware: Option<u8>,
     #[cfg_attr(not(feature = "debug_full_ct"), debug(ignore))]
-    table: &'static std::collections::HashMap<Register, RegisterData>,
+    table: &'static RegisterTable,
 }
 
 impl ControlTable {
@@ -42,6 +169,7 @@ impl ControlTable {
         ControlTable {
             model: None,
             model_group,
+            firmware: None,
             table,
         }
     }
@@ -53,25 +181,169 @@ impl ControlTable {
         ControlTable {
             model: Some(model),
             model_group,
+            firmware: None,
             table,
         }
     }
 
+    /// Create a ControlTable with an exact [`Model`] and the value of its [`Register::FirmwareVersion`] register.
+    /// Registers added in a later firmware version are treated as missing, see [`RegisterError::Firmware`].
+    pub fn new_with_firmware(model: Model, firmware_version: u8) -> Self {
+        ControlTable {
+            firmware: Some(firmware_version),
+            ..Self::new_with_model(model)
+        }
+    }
+
     /// Get the [`Model`] for this [`ControlTable`]. Returns an `Option` as there may not be an exact [`Model`] and only a [`ModelGroup`]
     pub fn model(&self) -> Option<Model> {
         self.model
     }
 
+    /// Get the firmware version for this [`ControlTable`]. Returns `None` if it was created without one,
+    /// in which case every register of the latest firmware is available.
+    pub fn firmware(&self) -> Option<u8> {
+        self.firmware
+    }
+
     /// Get the [`ModelGroup`] for this [`ControlTable`]
     pub fn model_group(&self) -> ModelGroup {
         self.model_group
     }
 
+    /// Get the [`Model`] if known, otherwise the [`ModelGroup`] for this [`ControlTable`]
+    pub fn model_or_group(&self) -> ModelOrModelGroup {
+        self.model.map_or(self.model_group.into(), Into::into)
+    }
+
+    /// Get the version of the protocol the [`ControlTable`] is defined for
+    pub fn protocol(&self) -> Protocol {
+        self.model_group.protocol()
+    }
+
     /// Get the register data for a specific register.
-    pub fn get(&self, register: Register) -> Result<&RegisterData, RegisterError> {
+    /// Returns [`RegisterError::Firmware`] if the register was added in a later firmware version than [`ControlTable::firmware`].
+    pub fn get(&self, register: Register) -> Result<&'static RegisterData, RegisterError> {
+        let data = self
+            .table
+            .get(register)
+            .ok_or_else(|| RegisterError::new(self.model, self.model_group, register))?;
+        match (self.table.min_firmware(register), self.firmware) {
+            (Some(required), Some(firmware)) if firmware < required => {
+                Err(RegisterError::Firmware {
+                    model_or_group: self.model_or_group(),
+                    register,
+                    required,
+                    firmware,
+                })
+            }
+            _ => Ok(data),
+        }
+    }
+
+    /// Get the firmware version a register was added in.
+    /// Returns `None` if the register is present in every firmware version.
+    pub fn min_firmware(&self, register: Register) -> Result<Option<u8>, RegisterError> {
+        self.get(register)?;
+        Ok(self.table.min_firmware(register))
+    }
+
+    /// Iterate over the registers of this [`ControlTable`] and their [`RegisterData`], sorted by address.
+    /// Registers the firmware predates are skipped.
+    pub fn registers(&self) -> impl Iterator<Item = (Register, RegisterData)> + '_ {
         self.table
-            .get(&register)
-            .ok_or_else(|| RegisterError::new(self.model, self.model_group, register))
+            .iter()
+            .filter(|(register, _)| self.get(*register).is_ok())
+            .map(|(register, data)| (register, *data))
+    }
+
+    /// Get the number of registers of this [`ControlTable`]
+    pub fn register_count(&self) -> usize {
+        match self.firmware {
+            Some(_) => self.registers().count(),
+            None => self.table.len(),
+        }
+    }
+
+    /// Get the Indirect Address and Indirect Data blocks of this [`ControlTable`].
+    /// Empty if the model has no Indirect registers.
+    pub fn indirect_blocks(&self) -> &'static [IndirectBlock] {
+        self.table.indirect_blocks()
+    }
+
+    /// Get the initial (factory default) value of a register.
+    /// Returns `None` if the register has no initial value, or if the [`ControlTable`] has no exact
+    /// [`Model`] and the initial value differs between the models of the [`ModelGroup`].
+    pub fn initial_value(&self, register: Register) -> Result<Option<Value>, RegisterError> {
+        self.get(register)?;
+        Ok(crate::models::initial_value_from_model_or_group(
+            self.model_or_group(),
+            register,
+        ))
+    }
+
+    /// Get the range of valid values of a register.
+    /// Returns `None` if the register has no range, or if the [`ControlTable`] has no exact
+    /// [`Model`] and the range differs between the models of the [`ModelGroup`].
+    pub fn range(&self, register: Register) -> Result<Option<ValueRange>, RegisterError> {
+        self.get(register)?;
+        Ok(crate::models::range_from_model_or_group(
+            self.model_or_group(),
+            register,
+        ))
+    }
+
+    /// Get the physical unit of a register.
+    /// Returns `None` if the register has no unit, or if the [`ControlTable`] has no exact
+    /// [`Model`] and the unit differs between the models of the [`ModelGroup`].
+    pub fn unit(&self, register: Register) -> Result<Option<Unit>, RegisterError> {
+        self.get(register)?;
+        Ok(crate::models::unit_from_model_or_group(
+            self.model_or_group(),
+            register,
+        ))
+    }
+}
+
+impl ModelTable for ControlTable {
+    fn model_or_group(&self) -> ModelOrModelGroup {
+        self.model_or_group()
+    }
+
+    fn get(&self, register: Register) -> Result<&'static RegisterData, RegisterError> {
+        self.get(register)
+    }
+
+    fn unit(&self, register: Register) -> Result<Option<Unit>, RegisterError> {
+        self.unit(register)
+    }
+
+    fn range(&self, register: Register) -> Result<Option<ValueRange>, RegisterError> {
+        self.range(register)
+    }
+
+    fn initial_value(&self, register: Register) -> Result<Option<Value>, RegisterError> {
+        self.initial_value(register)
+    }
+
+    fn min_firmware(&self, register: Register) -> Result<Option<u8>, RegisterError> {
+        self.min_firmware(register)
+    }
+
+    fn registers(&self) -> impl Iterator<Item = (Register, RegisterData)> {
+        self.registers()
+    }
+
+    fn register_count(&self) -> usize {
+        self.register_count()
+    }
+
+    fn protocol(&self) -> Protocol {
+        self.protocol()
+    }
+
+    fn indirect_blocks(&self) -> &'static [IndirectBlock] {
+        self.indirect_blocks()
     }
 }
 
@@ -159,10 +431,13 @@ mod serde_tests {
 
 #[cfg(test)]
 mod test {
-    use dynamixel_registers::{models::Model, Register};
+    use dynamixel_registers::models::{Model, ModelGroup, Protocol};
+    use dynamixel_registers::{
+        Bound, OperatingMode, Register, Value, ValueError, ValueRange, ValueType,
+    };
 
     use crate::control_table::RegisterError;
-    use crate::ControlTable;
+    use crate::{ControlTable, ModelTable};
 
     #[test]
     fn test_register_error() {
@@ -178,4 +453,476 @@ mod test {
             RegisterError::new(Some(model), model.into(), register)
         );
     }
+
+    #[test]
+    fn test_get_writable() {
+        let model = Model::XM430_W350;
+        let control_table = ControlTable::new_with_model(model);
+
+        assert!(control_table.get_writable(Register::GoalPosition).is_ok());
+        assert_eq!(
+            control_table
+                .get_writable(Register::PresentPosition)
+                .inspect_err(|e| println!("{e}"))
+                .unwrap_err(),
+            RegisterError::ReadOnly {
+                model_or_group: model.into(),
+                register: Register::PresentPosition
+            }
+        );
+        assert_eq!(
+            control_table.get_writable(Register::ErrorCode).unwrap_err(),
+            RegisterError::new(Some(model), model.into(), Register::ErrorCode)
+        );
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let control_table = ControlTable::new_with_model(Model::XM430_W350);
+
+        let goal_position = control_table.get(Register::GoalPosition).unwrap();
+        let mut buffer = [0; 4];
+        goal_position.encode(-2048, &mut buffer).unwrap();
+        assert_eq!(goal_position.decode(&buffer), Ok(Value::I32(-2048)));
+        assert_eq!(
+            goal_position.encode(2048u32, &mut buffer),
+            Err(ValueError::TypeMismatch {
+                expected: ValueType::I32,
+                actual: ValueType::U32
+            })
+        );
+
+        let model_number = control_table.get(Register::ModelNumber).unwrap();
+        assert_eq!(model_number.decode(&[0xFC, 0x03]), Ok(Value::U16(1020)));
+        assert_eq!(
+            model_number.decode(&buffer),
+            Err(ValueError::LengthMismatch {
+                expected: 2,
+                actual: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_initial_value() {
+        let control_table = ControlTable::new_with_model(Model::XM430_W210);
+        assert_eq!(
+            control_table.initial_value(Register::ModelNumber),
+            Ok(Some(Value::U16(1030)))
+        );
+        assert_eq!(
+            control_table.initial_value(Register::VelocityLimit),
+            Ok(Some(Value::U32(330)))
+        );
+        assert_eq!(
+            control_table.initial_value(Register::GoalPosition),
+            Ok(None)
+        );
+
+        let control_table = ControlTable::new(ModelGroup::XM430);
+        assert_eq!(control_table.initial_value(Register::ModelNumber), Ok(None));
+        assert_eq!(
+            control_table.initial_value(Register::CurrentLimit),
+            Ok(Some(Value::U16(1193)))
+        );
+        assert_eq!(
+            control_table.initial_value(Register::OperatingMode),
+            Ok(Some(Value::U8(3)))
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let control_table = ControlTable::new_with_model(Model::XL430_W250);
+        assert_eq!(
+            control_table.range(Register::VelocityLimit),
+            Ok(Some(ValueRange {
+                min: Bound::Value(0),
+                max: Bound::Value(1023)
+            }))
+        );
+        assert!(control_table.validate(Register::VelocityLimit, 265).is_ok());
+        assert_eq!(
+            control_table
+                .validate(Register::VelocityLimit, 5000)
+                .inspect_err(|e| println!("{e}"))
+                .unwrap_err(),
+            RegisterError::OutOfRange {
+                model_or_group: Model::XL430_W250.into(),
+                register: Register::VelocityLimit,
+                value: 5000,
+                min: 0,
+                max: 1023,
+            }
+        );
+
+        // Goal Velocity is limited by Velocity Limit
+        assert!(control_table
+            .validate(Register::GoalVelocity, -1023)
+            .is_ok());
+        assert!(control_table
+            .validate(Register::GoalVelocity, 1024)
+            .is_err());
+        assert!(control_table
+            .validate_with(Register::GoalVelocity, 300, |register| {
+                (register == Register::VelocityLimit).then_some(265)
+            })
+            .is_err());
+
+        // Goal Position is limited by the position limits in Position Control Mode
+        let mode = |mode: OperatingMode| {
+            move |register| (register == Register::OperatingMode).then_some(mode as i64)
+        };
+        assert!(control_table
+            .validate_with(Register::GoalPosition, 4095, mode(OperatingMode::Position))
+            .is_ok());
+        assert!(control_table
+            .validate_with(Register::GoalPosition, -1, mode(OperatingMode::Position))
+            .is_err());
+        // but not in Extended Position Control Mode, or when the mode is not known
+        assert!(control_table
+            .validate_with(
+                Register::GoalPosition,
+                -1,
+                mode(OperatingMode::ExtendedPosition)
+            )
+            .is_ok());
+        assert!(control_table.validate(Register::GoalPosition, -1).is_ok());
+        // the grippers have no multi-turn mode
+        assert!(ControlTable::new(ModelGroup::RHP12RN)
+            .validate(Register::GoalPosition, 1151)
+            .is_err());
+
+        // Present Position has no range so is limited by its value type
+        assert!(control_table
+            .validate(Register::PresentPosition, i32::MIN)
+            .is_ok());
+        assert!(control_table
+            .validate(Register::PresentPosition, i64::from(i32::MAX) + 1)
+            .is_err());
+    }
+
+    #[test]
+    fn test_model_range() {
+        let control_table = ControlTable::new_with_model(Model::XH430_W350);
+        assert_eq!(
+            control_table.range(Register::CurrentLimit),
+            Ok(Some(ValueRange {
+                min: Bound::Value(0),
+                max: Bound::Value(648)
+            }))
+        );
+        assert!(control_table.validate(Register::GoalCurrent, -648).is_ok());
+        assert!(control_table.validate(Register::GoalCurrent, 1193).is_err());
+
+        let control_table = ControlTable::new(ModelGroup::XM430);
+        assert!(control_table.validate(Register::GoalCurrent, 1193).is_ok());
+    }
+
+    #[test]
+    fn test_default_image() {
+        let control_table = ControlTable::new_with_model(Model::XL430_W250);
+        let image = control_table.default_image();
+
+        assert_eq!(image.len(), 148);
+        assert_eq!(image[0..2], 1060u16.to_le_bytes());
+        assert_eq!(image[7], 1);
+        assert_eq!(image[11], 3);
+        assert_eq!(image[44..48], 265u32.to_le_bytes());
+        assert_eq!(image[116..120], [0; 4]);
+    }
+
+    #[test]
+    fn test_default_image_validates() {
+        for model in [Model::XL430_W250, Model::XM430_W350, Model::PH54_200_S500_R] {
+            let control_table = ControlTable::new_with_model(model);
+            for (register, _) in control_table.registers() {
+                if let Some(value) = control_table.initial_value(register).unwrap() {
+                    assert_eq!(
+                        control_table.validate(register, value),
+                        Ok(()),
+                        "{register}"
+                    );
+                }
+            }
+        }
+        // the disable values are only valid because they are the initial values
+        let control_table = ControlTable::new_with_model(Model::XM430_W350);
+        assert!(control_table.validate(Register::BusWatchdog, 0).is_ok());
+        assert!(control_table.validate(Register::BusWatchdog, 128).is_err());
+    }
+
+    #[test]
+    fn test_get_writable_with_torque() {
+        let model = Model::XL430_W250;
+        let control_table = ControlTable::new_with_model(model);
+
+        assert!(control_table
+            .get_writable_with_torque(Register::OperatingMode, false)
+            .is_ok());
+        assert!(control_table
+            .get_writable_with_torque(Register::GoalPosition, true)
+            .is_ok());
+        assert_eq!(
+            control_table
+                .get_writable_with_torque(Register::OperatingMode, true)
+                .inspect_err(|e| println!("{e}"))
+                .unwrap_err(),
+            RegisterError::EepromLocked {
+                model_or_group: model.into(),
+                register: Register::OperatingMode
+            }
+        );
+        assert_eq!(
+            control_table
+                .get_writable_with_torque(Register::ModelNumber, false)
+                .unwrap_err(),
+            RegisterError::ReadOnly {
+                model_or_group: model.into(),
+                register: Register::ModelNumber
+            }
+        );
+    }
+
+    #[test]
+    fn test_si_conversion() {
+        let control_table = ControlTable::new_with_model(Model::XM430_W350);
+        let velocity = control_table.to_si(Register::GoalVelocity, 100).unwrap();
+        assert!((velocity - 2.398).abs() < 1e-3);
+        assert_eq!(
+            control_table.from_si(Register::GoalVelocity, velocity),
+            Ok(Value::I32(100))
+        );
+        assert_eq!(
+            control_table.from_si(Register::GoalCurrent, 1.0),
+            Ok(Value::I16(372))
+        );
+        assert!(matches!(
+            control_table.from_si(Register::PwmLimit, -1.0),
+            Err(RegisterError::OutOfRange { .. })
+        ));
+        assert_eq!(
+            control_table.to_si(Register::OperatingMode, 3),
+            Err(RegisterError::NoUnit {
+                model_or_group: Model::XM430_W350.into(),
+                register: Register::OperatingMode
+            })
+        );
+
+        // Current resolution differs between the models of the XH430 group
+        let control_table = ControlTable::new_with_model(Model::XH430_V350);
+        assert_eq!(
+            control_table.from_si(Register::GoalCurrent, 1.0),
+            Ok(Value::I16(746))
+        );
+        let control_table = ControlTable::new(ModelGroup::XH430);
+        assert_eq!(control_table.unit(Register::GoalCurrent), Ok(None));
+        assert!(control_table
+            .unit(Register::GoalPosition)
+            .unwrap()
+            .is_some());
+
+        let control_table = ControlTable::new_with_model(Model::PH54_200_S500_R);
+        let position = control_table
+            .to_si(Register::GoalPosition, 1003846)
+            .unwrap();
+        assert!((position - core::f32::consts::TAU).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_table_lookup() {
+        let control_table = ControlTable::new(ModelGroup::XM430);
+        let copy = control_table;
+        assert_eq!(copy, control_table);
+        assert_eq!(
+            control_table.get(Register::GoalPosition),
+            Ok(&crate::models::XM430::GOAL_POSITION)
+        );
+        assert_eq!(
+            control_table.get(Register::PresentLoad),
+            Err(RegisterError::new(
+                None,
+                ModelGroup::XM430,
+                Register::PresentLoad
+            ))
+        );
+    }
+
+    #[test]
+    fn test_register_at() {
+        let control_table = ControlTable::new(ModelGroup::XL430);
+        let register = control_table.register_at(117).unwrap();
+        assert_eq!(register.register, Register::GoalPosition);
+        assert_eq!(register.offset, 1);
+        assert_eq!(control_table.register_at(66), None);
+
+        // RealtimeTick is listed after the present values in the control table but sorted by address
+        let registers = control_table
+            .registers_in(114..124)
+            .map(|r| (r.register, r.offset))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            registers,
+            [
+                (Register::ProfileVelocity, 2),
+                (Register::GoalPosition, 0),
+                (Register::RealtimeTick, 0),
+                (Register::Moving, 0),
+                (Register::MovingStatus, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_registers() {
+        let control_table = ControlTable::new(ModelGroup::XL430);
+        assert_eq!(
+            control_table.registers().count(),
+            control_table.register_count()
+        );
+        assert_eq!(
+            control_table.register_count(),
+            crate::models::XL430::register_count()
+        );
+        assert!(control_table
+            .registers()
+            .zip(control_table.registers().skip(1))
+            .all(|((_, a), (_, b))| a.address < b.address));
+        assert_eq!(
+            control_table.registers().next(),
+            Some((Register::ModelNumber, crate::models::XL430::MODEL_NUMBER))
+        );
+        assert!(crate::models::XL430::registers().eq(control_table.registers()));
+    }
+
+    #[test]
+    fn test_xw430() {
+        let control_table = ControlTable::from(Model::try_from(1280).unwrap());
+        assert_eq!(control_table.model_group(), ModelGroup::XW430);
+        assert_eq!(
+            control_table.get(Register::GoalCurrent).unwrap().address,
+            102
+        );
+        assert_eq!(
+            control_table.initial_value(Register::CurrentLimit),
+            Ok(Some(Value::U16(1193)))
+        );
+        // waterproof, so there is no LED
+        assert!(control_table.get(Register::Led).is_err());
+        assert_eq!(
+            ControlTable::new(ModelGroup::XW430).indirect_blocks(),
+            crate::models::XW540::indirect_blocks()
+        );
+    }
+
+    #[test]
+    fn test_firmware() {
+        let control_table = ControlTable::new_with_firmware(Model::XM430_W350, 37);
+        assert_eq!(control_table.firmware(), Some(37));
+        assert_eq!(
+            control_table.get(Register::BusWatchdog),
+            Err(RegisterError::Firmware {
+                model_or_group: Model::XM430_W350.into(),
+                register: Register::BusWatchdog,
+                required: 38,
+                firmware: 37
+            })
+        );
+        assert!(control_table.get(Register::GoalPosition).is_ok());
+        assert_eq!(
+            control_table.register_count(),
+            ControlTable::new_with_model(Model::XM430_W350).register_count() - 3
+        );
+        assert!(control_table
+            .registers()
+            .all(|(register, _)| register != Register::StartupConfiguration));
+
+        let control_table = ControlTable::new_with_firmware(Model::XM430_W350, 45);
+        assert_eq!(
+            control_table.min_firmware(Register::BackupReady),
+            Ok(Some(45))
+        );
+        assert_eq!(control_table.min_firmware(Register::GoalPosition), Ok(None));
+        assert_eq!(
+            ControlTable::new_with_model(Model::XM430_W350).firmware(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_grippers() {
+        // the RH-P12-RN has the layout of the older PRO series, and the RH-P12-RN(A) that of the P series
+        let control_table = ControlTable::from(Model::try_from(35073).unwrap());
+        assert_eq!(control_table.model_group(), ModelGroup::RHP12RN);
+        assert_eq!(
+            control_table.get(Register::GoalPosition).unwrap().address,
+            596
+        );
+        assert_eq!(control_table.indirect_blocks()[0].address_span().end(), 561);
+
+        let control_table = ControlTable::new_with_model(Model::RH_P12_RNA);
+        assert_eq!(control_table.protocol(), Protocol::V2);
+        assert_eq!(
+            control_table.get(Register::GoalPosition).unwrap().address,
+            ControlTable::new(ModelGroup::PH54)
+                .get(Register::GoalPosition)
+                .unwrap()
+                .address
+        );
+        assert_eq!(
+            control_table.initial_value(Register::OperatingMode),
+            Ok(Some(Value::U8(5)))
+        );
+    }
+
+    #[test]
+    fn test_xl320() {
+        let control_table = ControlTable::from(Model::try_from(350).unwrap());
+        assert_eq!(control_table.model_group(), ModelGroup::XL320);
+        assert_eq!(control_table.protocol(), Protocol::V2);
+        assert_eq!(
+            control_table.get(Register::TorqueLimit).unwrap().address,
+            35
+        );
+        // the LED register sets a colour rather than on or off
+        assert_eq!(
+            control_table.range(Register::Led),
+            Ok(Some(ValueRange {
+                min: Bound::Value(0),
+                max: Bound::Value(7)
+            }))
+        );
+        assert!(control_table.validate(Register::ControlMode, 2).is_ok());
+        assert!(control_table.validate(Register::ControlMode, 3).is_err());
+        assert!(control_table.get(Register::OperatingMode).is_err());
+    }
+
+    #[test]
+    fn test_protocol_1() {
+        let control_table = ControlTable::new_with_model(Model::AX_12A);
+        assert_eq!(control_table.protocol(), Protocol::V1);
+        assert_eq!(control_table.model_group(), ModelGroup::AX12);
+        assert_eq!(
+            control_table.get(Register::GoalPosition).unwrap().address,
+            30
+        );
+        assert_eq!(
+            control_table.initial_value(Register::ModelNumber),
+            Ok(Some(Value::U16(12)))
+        );
+
+        // the MX-64 adds current control to the MX-28 table
+        let mx28 = ControlTable::new_with_model(Model::MX_28);
+        let mx64 = ControlTable::new_with_model(Model::MX_64);
+        assert!(mx28.get(Register::GoalTorque).is_err());
+        assert_eq!(mx64.get(Register::GoalTorque).unwrap().address, 71);
+        assert_eq!(
+            mx64.get(Register::PresentPosition),
+            mx28.get(Register::PresentPosition)
+        );
+        assert_eq!(
+            ControlTable::new_with_model(Model::XM430_W350).protocol(),
+            Protocol::V2
+        );
+    }
 }
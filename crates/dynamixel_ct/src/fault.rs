@@ -0,0 +1,354 @@
+//! Faults reported by a servo.
+//!
+//! Most models report faults as flags in [`Register::HardwareErrorStatus`], while the Y series reports a single code
+//! in [`Register::ErrorCode`], with the state of the controller in [`Register::ControllerState`].
+//! [`ModelTable::fault_registers`](crate::ModelTable::fault_registers) tells which registers to read,
+//! and [`ModelTable::decode_fault`](crate::ModelTable::decode_fault) decodes them into a [`Fault`].
+//! Protocol 1.0 models have no such register, they only report faults in the error byte of each status packet.
+//!
+//! ## Example
+//! ```rust
+//! use dynamixel_ct::fault::{ControllerState, ErrorCode, Fault, Severity};
+//! use dynamixel_ct::{ControlTable, Model, ModelTable, Register};
+//!
+//! let control_table = ControlTable::new_with_model(Model::YM070_210_M001_RH);
+//! assert_eq!(
+//!     control_table.fault_registers(),
+//!     &[Register::ControllerState, Register::ErrorCode]
+//! );
+//! let span = control_table
+//!     .spans(control_table.fault_registers(), 0)
+//!     .unwrap()
+//!     .next()
+//!     .unwrap();
+//! // the bytes read from the span
+//! let fault = control_table.decode_fault(span.address, &[4, 0x04]).unwrap();
+//! assert_eq!(
+//!     fault,
+//!     Fault::ErrorCode {
+//!         code: ErrorCode::MotorOverheating,
+//!         state: ControllerState::Fault
+//!     }
+//! );
+//! assert_eq!(fault.severity(), Some(Severity::Error));
+//! ```
+
+use dynamixel_registers::Register;
+
+use crate::bitfield::Bitfield;
+
+/// How serious a fault is
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, derive_more::Display)]
+pub enum Severity {
+    /// The servo keeps running, but the cause should be fixed
+    Warning,
+    /// The servo has stopped, torque is disabled until the fault is cleared or the servo is rebooted
+    Error,
+}
+
+/// The value of [`Register::ErrorCode`] of the Y series,
+/// as listed in the Error Code(153) table of the YM070 / YM080 e-Manual.
+/// The codes are grouped by cause, so the table has gaps (e.g. `0x06`, `0x08`, `0x10`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, derive_more::Display)]
+#[repr(u8)]
+pub enum ErrorCode {
+    /// No error
+    #[display("No Error")]
+    None = 0x00,
+    /// The input voltage is above [`Register::MaxVoltageLimit`]
+    #[display("Over Voltage Error")]
+    OverVoltage = 0x01,
+    /// The input voltage is below [`Register::MinVoltageLimit`]
+    #[display("Low Voltage Error")]
+    LowVoltage = 0x02,
+    /// The inverter temperature is above [`Register::InverterTemperatureLimit`]
+    #[display("Inverter Overheating Error")]
+    InverterOverheating = 0x03,
+    /// The motor temperature is above [`Register::MotorTemperatureLimit`]
+    #[display("Motor Overheating Error")]
+    MotorOverheating = 0x04,
+    /// The load has been above the rated load for too long
+    #[display("Overload Error")]
+    Overload = 0x05,
+    /// The inverter detected a fault, e.g. an over current
+    #[display("Inverter Error")]
+    Inverter = 0x07,
+    /// The battery keeping the multi-turn position of the encoder is low
+    #[display("Battery Warning")]
+    BatteryWarning = 0x09,
+    /// The battery keeping the multi-turn position of the encoder is empty or disconnected
+    #[display("Battery Error")]
+    Battery = 0x0A,
+    /// The encoder can't detect its magnet
+    #[display("Magnet Error")]
+    Magnet = 0x0B,
+    /// The multi-turn count of the encoder is invalid
+    #[display("Multi-turn Error")]
+    MultiTurn = 0x0C,
+    /// The encoder doesn't respond or reports an invalid position
+    #[display("Encoder Error")]
+    Encoder = 0x0D,
+    /// The hall sensors of the motor report an invalid state
+    #[display("Hall Sensor Error")]
+    HallSensor = 0x0E,
+    /// The encoder is not calibrated
+    #[display("Calibration Error")]
+    Calibration = 0x0F,
+    /// The position error is above [`Register::FollowingErrorThreshold`]
+    #[display("Following Error")]
+    Following = 0x11,
+    /// No instruction was received within [`Register::BusWatchdog`]
+    #[display("Bus Watchdog Error")]
+    BusWatchdog = 0x12,
+    /// The velocity is above [`Register::VelocityLimit`]
+    #[display("Over Speed Error")]
+    OverSpeed = 0x13,
+    /// The position is past [`Register::PositionLimitThreshold`] outside the position limits
+    #[display("Position Limit Reached Error")]
+    PositionLimit = 0x14,
+}
+
+impl ErrorCode {
+    /// A description of the cause of the error
+    pub const fn description(&self) -> &'static str {
+        match self {
+            ErrorCode::None => "no error",
+            ErrorCode::OverVoltage => "the input voltage is above the Max Voltage Limit",
+            ErrorCode::LowVoltage => "the input voltage is below the Min Voltage Limit",
+            ErrorCode::InverterOverheating => {
+                "the inverter temperature is above the Inverter Temperature Limit"
+            }
+            ErrorCode::MotorOverheating => {
+                "the motor temperature is above the Motor Temperature Limit"
+            }
+            ErrorCode::Overload => "the load has been above the rated load for too long",
+            ErrorCode::Inverter => "the inverter detected a fault, e.g. an over current",
+            ErrorCode::BatteryWarning => "the multi-turn battery of the encoder is low",
+            ErrorCode::Battery => "the multi-turn battery of the encoder is empty or disconnected",
+            ErrorCode::Magnet => "the encoder can't detect its magnet",
+            ErrorCode::MultiTurn => "the multi-turn count of the encoder is invalid",
+            ErrorCode::Encoder => "the encoder doesn't respond or reports an invalid position",
+            ErrorCode::HallSensor => "the hall sensors of the motor report an invalid state",
+            ErrorCode::Calibration => "the encoder is not calibrated",
+            ErrorCode::Following => "the position error is above the Following Error Threshold",
+            ErrorCode::BusWatchdog => "no instruction was received within the Bus Watchdog time",
+            ErrorCode::OverSpeed => "the velocity is above the Velocity Limit",
+            ErrorCode::PositionLimit => "the position is outside the position limits",
+        }
+    }
+
+    /// How serious the error is, or `None` if there is no error
+    pub const fn severity(&self) -> Option<Severity> {
+        match self {
+            ErrorCode::None => None,
+            ErrorCode::BatteryWarning => Some(Severity::Warning),
+            _ => Some(Severity::Error),
+        }
+    }
+}
+
+/// The value of [`Register::ControllerState`] of the Y series
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, derive_more::Display)]
+#[repr(u8)]
+pub enum ControllerState {
+    /// The controller is starting up
+    #[display("Initializing")]
+    Init = 0,
+    /// Torque is disabled and the servo is ready to be enabled
+    #[display("Ready")]
+    Ready = 1,
+    /// Torque is enabled and the servo is following its goal
+    #[display("Running")]
+    Running = 2,
+    /// The servo is stopping with the brake
+    #[display("Braking")]
+    Braking = 3,
+    /// The servo stopped because of an error, see [`ErrorCode`]
+    #[display("Fault")]
+    Fault = 4,
+}
+
+impl ControllerState {
+    /// A description of the state
+    pub const fn description(&self) -> &'static str {
+        match self {
+            ControllerState::Init => "the controller is starting up",
+            ControllerState::Ready => "torque is disabled and the servo is ready to be enabled",
+            ControllerState::Running => "torque is enabled and the servo is following its goal",
+            ControllerState::Braking => "the servo is stopping with the brake",
+            ControllerState::Fault => "the servo stopped because of an error",
+        }
+    }
+}
+
+/// The value of the register is not a known code
+#[derive(Debug, Clone, Copy, derive_more::Error, derive_more::Display, PartialEq)]
+#[display("{value:#04X} is not a known {register} value")]
+pub struct UnknownCode {
+    /// The register the value was read from
+    pub register: Register,
+    /// The value read
+    pub value: u8,
+}
+
+impl TryFrom<u8> for ErrorCode {
+    type Error = UnknownCode;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        use ErrorCode::*;
+        [
+            None,
+            OverVoltage,
+            LowVoltage,
+            InverterOverheating,
+            MotorOverheating,
+            Overload,
+            Inverter,
+            BatteryWarning,
+            Battery,
+            Magnet,
+            MultiTurn,
+            Encoder,
+            HallSensor,
+            Calibration,
+            Following,
+            BusWatchdog,
+            OverSpeed,
+            PositionLimit,
+        ]
+        .into_iter()
+        .find(|code| *code as u8 == value)
+        .ok_or(UnknownCode {
+            register: Register::ErrorCode,
+            value,
+        })
+    }
+}
+
+impl TryFrom<u8> for ControllerState {
+    type Error = UnknownCode;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        use ControllerState::*;
+        [Init, Ready, Running, Braking, Fault]
+            .into_iter()
+            .find(|state| *state as u8 == value)
+            .ok_or(UnknownCode {
+                register: Register::ControllerState,
+                value,
+            })
+    }
+}
+
+/// The faults read from the registers given by [`ModelTable::fault_registers`](crate::ModelTable::fault_registers)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, derive_more::Display)]
+pub enum Fault {
+    /// The flags of [`Register::HardwareErrorStatus`]
+    #[display("{_0}")]
+    Flags(Bitfield),
+    /// The code of [`Register::ErrorCode`] and the state of the controller of the Y series
+    #[display("{code} ({state})")]
+    ErrorCode {
+        /// The value of [`Register::ErrorCode`]
+        code: ErrorCode,
+        /// The value of [`Register::ControllerState`]
+        state: ControllerState,
+    },
+}
+
+impl Fault {
+    /// Check if the servo has a fault
+    pub fn is_fault(&self) -> bool {
+        self.severity().is_some()
+    }
+
+    /// How serious the fault is, or `None` if there is no fault.
+    /// Every hardware error flag stops the servo, so flags are always an [`Severity::Error`].
+    pub fn severity(&self) -> Option<Severity> {
+        match self {
+            Fault::Flags(flags) => (flags.bits() != 0).then_some(Severity::Error),
+            Fault::ErrorCode { code, .. } => code.severity(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitfield::HardwareError;
+    use crate::control_table::RegisterError;
+    use crate::models::{XL430, YM080};
+    use crate::{ControlTable, ModelGroup, ModelTable};
+
+    #[test]
+    fn test_error_code() {
+        assert_eq!(ErrorCode::try_from(0x11), Ok(ErrorCode::Following));
+        assert!(ErrorCode::try_from(0x06).is_err());
+        assert_eq!(
+            ErrorCode::try_from(0x42),
+            Err(UnknownCode {
+                register: Register::ErrorCode,
+                value: 0x42
+            })
+        );
+        assert_eq!(
+            ErrorCode::BatteryWarning.severity(),
+            Some(Severity::Warning)
+        );
+        assert_eq!(ErrorCode::None.severity(), None);
+        assert_eq!(ControllerState::try_from(4), Ok(ControllerState::Fault));
+    }
+
+    #[test]
+    fn test_decode_fault() {
+        assert_eq!(XL430.fault_registers(), &[Register::HardwareErrorStatus]);
+        let address = XL430.get(Register::HardwareErrorStatus).unwrap().address;
+        let fault = XL430.decode_fault(address, &[0x20]).unwrap();
+        assert_eq!(
+            fault,
+            Fault::Flags(Bitfield::HardwareError(HardwareError::OVERLOAD))
+        );
+        assert_eq!(fault.to_string(), "Overload Error");
+        assert!(!XL430.decode_fault(address, &[0]).unwrap().is_fault());
+        assert!(matches!(
+            XL430.decode_fault(address + 1, &[0x20]),
+            Err(RegisterError::NotRead { .. })
+        ));
+
+        assert!(ControlTable::new(ModelGroup::AX12)
+            .fault_registers()
+            .is_empty());
+        assert!(matches!(
+            ControlTable::new(ModelGroup::AX12).decode_fault(0, &[0x20]),
+            Err(RegisterError::Missing { .. })
+        ));
+
+        // the controller state and error code are read together
+        let span = YM080
+            .spans(YM080.fault_registers(), 0)
+            .unwrap()
+            .next()
+            .unwrap();
+        assert_eq!(span.length, 2);
+        let fault = YM080.decode_fault(span.address, &[4, 0x11]).unwrap();
+        assert_eq!(
+            fault,
+            Fault::ErrorCode {
+                code: ErrorCode::Following,
+                state: ControllerState::Fault
+            }
+        );
+        assert_eq!(fault.to_string(), "Following Error (Fault)");
+        assert!(!YM080
+            .decode_fault(span.address, &[2, 0])
+            .unwrap()
+            .is_fault());
+        assert!(matches!(
+            YM080.decode_fault(span.address, &[2, 0xFF]),
+            Err(RegisterError::UnknownValue {
+                register: Register::ErrorCode,
+                ..
+            })
+        ));
+    }
+}
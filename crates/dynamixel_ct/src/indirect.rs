@@ -0,0 +1,341 @@
+//! Indirect Address and Indirect Data.
+//!
+//! The X, P and Y series and the grippers have blocks of Indirect Address registers, each holding the address of a byte of the control table.
+//! Reading or writing the matching Indirect Data byte reads or writes the byte at that address,
+//! so registers spread across the control table can be read or written with one contiguous span.
+//!
+//! ## Example
+//! ```rust
+//! use dynamixel_ct::indirect::IndirectMap;
+//! use dynamixel_ct::models::XM430;
+//! use dynamixel_ct::{Register, Value};
+//!
+//! let map = IndirectMap::allocate(&XM430, &[Register::HardwareErrorStatus, Register::PresentPosition]).unwrap();
+//! // write the addresses of the registers into the Indirect Address registers
+//! let mut addresses = [0; 10];
+//! let span = map.encode_addresses(&mut addresses).unwrap();
+//! assert_eq!((span.address, span.length), (168, 10));
+//! assert_eq!(addresses[..4], [70, 0, 132, 0]);
+//!
+//! // then read both registers from the Indirect Data registers
+//! let span = map.data_span();
+//! assert_eq!((span.address, span.length), (224, 5));
+//! let values = map.decode(&[0x01, 0xA6, 0x00, 0x00, 0x00]).collect::<Vec<_>>();
+//! assert_eq!(values, [(Register::HardwareErrorStatus, Value::U8(1)), (Register::PresentPosition, Value::I32(166))]);
+//! ```
+
+use dynamixel_registers::{Register, Value, ValueError};
+
+use crate::control_table::RegisterError;
+use crate::{ModelTable, Span};
+
+/// A block of Indirect Address registers and the Indirect Data registers they map to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IndirectBlock {
+    /// The address of the first Indirect Address register. Each Indirect Address register is 2 bytes.
+    pub address: u16,
+    /// The address of the first Indirect Data register. Each Indirect Data register is 1 byte.
+    pub data: u16,
+    /// The number of Indirect Address and Indirect Data registers in the block
+    pub count: u16,
+}
+
+impl IndirectBlock {
+    /// The span of the Indirect Address registers
+    pub const fn address_span(&self) -> Span {
+        Span {
+            address: self.address,
+            length: self.count * 2,
+        }
+    }
+
+    /// The span of the Indirect Data registers
+    pub const fn data_span(&self) -> Span {
+        Span {
+            address: self.data,
+            length: self.count,
+        }
+    }
+}
+
+/// An error mapping registers to an [`IndirectBlock`]
+#[derive(Clone, Debug, PartialEq, derive_more::Display, derive_more::Error, derive_more::From)]
+pub enum IndirectError {
+    /// The registers don't fit in the Indirect Data registers left in the block
+    #[display("{required} indirect registers are required but only {available} are available")]
+    #[from(ignore)]
+    NoSpace {
+        /// The number of bytes of the registers
+        required: u16,
+        /// The number of Indirect Data registers left in the block
+        available: u16,
+    },
+    /// The register cannot be used with the model
+    #[display("{_0}")]
+    Register(RegisterError),
+}
+
+/// The registers mapped to a run of Indirect Data registers, in the order given.
+///
+/// Each byte of a register uses one Indirect Address and Indirect Data register.
+#[derive(Clone, Copy, Debug)]
+pub struct IndirectMap<'a, T: ModelTable> {
+    table: &'a T,
+    block: IndirectBlock,
+    start: u16,
+    length: u16,
+    registers: &'a [Register],
+}
+
+impl<'a, T: ModelTable> IndirectMap<'a, T> {
+    /// Map `registers` to the Indirect Data registers of `block`, starting at the `start`th entry of the block.
+    /// Returns [`IndirectError::NoSpace`] if the registers don't fit in the rest of the block,
+    /// and [`RegisterError::Missing`] if the model doesn't have one of the registers.
+    pub fn new(
+        table: &'a T,
+        block: IndirectBlock,
+        start: u16,
+        registers: &'a [Register],
+    ) -> Result<Self, IndirectError> {
+        let length = total_length(table, registers)?;
+        let available = block.count.saturating_sub(start);
+        if length > available {
+            return Err(IndirectError::NoSpace {
+                required: length,
+                available,
+            });
+        }
+        Ok(Self {
+            table,
+            block,
+            start,
+            length,
+            registers,
+        })
+    }
+
+    /// Map `registers` to the start of the first Indirect block of the model they fit in.
+    /// Returns [`IndirectError::NoSpace`] with the size of the largest block if they don't fit in any block.
+    pub fn allocate(table: &'a T, registers: &'a [Register]) -> Result<Self, IndirectError> {
+        let required = total_length(table, registers)?;
+        let mut available = 0;
+        for block in table.indirect_blocks() {
+            if required <= block.count {
+                return Self::new(table, *block, 0, registers);
+            }
+            available = available.max(block.count);
+        }
+        Err(IndirectError::NoSpace {
+            required,
+            available,
+        })
+    }
+
+    /// The block the registers are mapped to
+    pub fn block(&self) -> IndirectBlock {
+        self.block
+    }
+
+    /// The span of the Indirect Address registers to write, see [`IndirectMap::encode_addresses`]
+    pub fn address_span(&self) -> Span {
+        Span {
+            address: self.block.address + self.start * 2,
+            length: self.length * 2,
+        }
+    }
+
+    /// The span of the Indirect Data registers to read or write the mapped registers
+    pub fn data_span(&self) -> Span {
+        Span {
+            address: self.block.data + self.start,
+            length: self.length,
+        }
+    }
+
+    /// Iterate over the Indirect Address writes, as the address of the Indirect Address register and
+    /// the address of the byte it maps to
+    pub fn address_writes(&self) -> impl Iterator<Item = (u16, u16)> + 'a {
+        let table = self.table;
+        let address = self.address_span().address;
+        self.registers
+            .iter()
+            .filter_map(move |register| table.get(*register).ok())
+            .flat_map(|data| data.address..data.address + data.length)
+            .enumerate()
+            .map(move |(i, target)| (address + i as u16 * 2, target))
+    }
+
+    /// Write the little-endian bytes of the Indirect Address registers into `bytes`, returning the span to write them to.
+    /// Returns [`ValueError::LengthMismatch`] if `bytes` is not the length of [`IndirectMap::address_span`].
+    pub fn encode_addresses(&self, bytes: &mut [u8]) -> Result<Span, ValueError> {
+        let span = self.address_span();
+        if bytes.len() != span.length as usize {
+            return Err(ValueError::LengthMismatch {
+                expected: span.length,
+                actual: bytes.len(),
+            });
+        }
+        for ((_, target), chunk) in self.address_writes().zip(bytes.chunks_exact_mut(2)) {
+            chunk.copy_from_slice(&target.to_le_bytes());
+        }
+        Ok(span)
+    }
+
+    /// Get the address of the Indirect Data register a register is mapped to, or `None` if it is not mapped
+    pub fn data_address(&self, register: Register) -> Option<u16> {
+        let mut address = self.data_span().address;
+        for mapped in self.registers {
+            if *mapped == register {
+                return Some(address);
+            }
+            address += self.table.get(*mapped).ok()?.length;
+        }
+        None
+    }
+
+    /// Decode the mapped registers from `bytes` read from [`IndirectMap::data_span`].
+    /// Registers cut off by the end of `bytes` are skipped.
+    pub fn decode(&self, bytes: &'a [u8]) -> impl Iterator<Item = (Register, Value)> + 'a {
+        let table = self.table;
+        let mut start = 0;
+        self.registers.iter().filter_map(move |register| {
+            let data = table.get(*register).ok()?;
+            let end = start + data.length as usize;
+            let value = bytes.get(start..end).map(|bytes| data.decode(bytes));
+            start = end;
+            Some((*register, value?.ok()?))
+        })
+    }
+}
+
+/// The number of bytes of `registers`
+fn total_length(table: &impl ModelTable, registers: &[Register]) -> Result<u16, RegisterError> {
+    registers.iter().try_fold(0, |length, register| {
+        Ok(length + table.get(*register)?.length)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{PH54, XL430, XM430, YM080};
+    use crate::{ControlTable, Model};
+
+    #[test]
+    fn test_indirect_blocks() {
+        assert_eq!(
+            XM430.indirect_blocks(),
+            [
+                IndirectBlock {
+                    address: 168,
+                    data: 224,
+                    count: 20
+                },
+                IndirectBlock {
+                    address: 578,
+                    data: 634,
+                    count: 8
+                },
+            ]
+        );
+        let control_table = ControlTable::new_with_model(Model::PH54_200_S500_R);
+        assert_eq!(
+            control_table.indirect_blocks(),
+            [IndirectBlock {
+                address: 168,
+                data: 634,
+                count: 128
+            }]
+        );
+        // the Indirect Address registers end where the Indirect Data registers start
+        assert_eq!(XL430.indirect_blocks()[0].address_span().end(), 208);
+        // the Y series fills the end of the EEPROM area with Indirect Address registers
+        assert_eq!(
+            YM080.indirect_blocks(),
+            [IndirectBlock {
+                address: 256,
+                data: 634,
+                count: 128
+            }]
+        );
+        assert_eq!(YM080.indirect_blocks()[0].address_span().end(), 512);
+    }
+
+    #[test]
+    fn test_address_writes() {
+        let registers = [Register::GoalPosition, Register::TorqueEnable];
+        let map = IndirectMap::allocate(&XM430, &registers).unwrap();
+        assert_eq!(
+            map.address_writes().collect::<Vec<_>>(),
+            [(168, 116), (170, 117), (172, 118), (174, 119), (176, 64)]
+        );
+        assert_eq!(map.data_address(Register::TorqueEnable), Some(228));
+        assert_eq!(map.data_address(Register::Led), None);
+        assert_eq!(
+            map.encode_addresses(&mut [0; 4]),
+            Err(ValueError::LengthMismatch {
+                expected: 10,
+                actual: 4
+            })
+        );
+
+        // a later entry of the second block
+        let map = IndirectMap::new(&XM430, XM430.indirect_blocks()[1], 2, &registers).unwrap();
+        assert_eq!(
+            map.address_span(),
+            Span {
+                address: 582,
+                length: 10
+            }
+        );
+        assert_eq!(
+            map.data_span(),
+            Span {
+                address: 636,
+                length: 5
+            }
+        );
+    }
+
+    #[test]
+    fn test_allocate() {
+        // 25 bytes don't fit in either X series block, but do in the P series block
+        let registers = [
+            Register::PresentPwm,
+            Register::PresentCurrent,
+            Register::PresentVelocity,
+            Register::PresentPosition,
+            Register::VelocityTrajectory,
+            Register::PositionTrajectory,
+            Register::PresentInputVoltage,
+            Register::PresentTemperature,
+            Register::Moving,
+            Register::MovingStatus,
+        ];
+        assert_eq!(
+            IndirectMap::allocate(&XM430, &registers).map(|map| map.block()),
+            Err(IndirectError::NoSpace {
+                required: 25,
+                available: 20
+            })
+        );
+        let map = IndirectMap::allocate(&PH54, &registers).unwrap();
+        assert_eq!(map.data_span().address, 634);
+
+        let map = IndirectMap::allocate(&XM430, &registers[8..]).unwrap();
+        let values = map.decode(&[1, 3]).collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            [
+                (Register::Moving, Value::U8(1)),
+                (Register::MovingStatus, Value::U8(3))
+            ]
+        );
+        assert!(map.decode(&[1]).eq([(Register::Moving, Value::U8(1))]));
+
+        assert!(matches!(
+            IndirectMap::allocate(&XL430, &[Register::PresentCurrent]),
+            Err(IndirectError::Register(RegisterError::Missing { .. }))
+        ));
+    }
+}
@@ -33,14 +33,25 @@
 #![warn(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
-#[cfg(feature = "std")]
+pub mod bitfield;
+pub mod capabilities;
 pub mod control_table;
+pub mod fault;
+pub mod indirect;
 mod model_macro;
+pub mod model_table;
 pub mod models;
-
+pub mod packet;
 #[cfg(feature = "std")]
+pub mod plan;
+
+pub use capabilities::Capabilities;
 pub use control_table::ControlTable;
-pub use dynamixel_registers::models::{Model, ModelGroup, ModelOrModelGroup};
-pub use dynamixel_registers::{Register, RegisterData};
+pub use dynamixel_registers::models::{Model, ModelGroup, ModelOrModelGroup, Protocol};
+pub use dynamixel_registers::{
+    Access, Area, Bound, OperatingMode, Quantity, Register, RegisterData, Unit, Value, ValueError,
+    ValueRange, ValueType,
+};
+pub use model_table::{ModelTable, RegisterOffset, Span};
 
 pub(crate) use model_macro::model;
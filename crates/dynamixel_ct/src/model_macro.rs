@@ -1,19 +1,88 @@
 //! The [`model!`] macro definition.
 //!
 
+use dynamixel_registers::models::{Model, ModelOrModelGroup};
+use dynamixel_registers::{Register, RegisterData};
+
+use crate::indirect::IndirectBlock;
+
 /// The [`model!`] macro is used to define the control table for a specific model.
-/// It creates a static HashMap of RegisterData for each register in the model.
+/// It creates a static [`RegisterTable`] of RegisterData for each register in the model.
+///
+/// Each register can have a unit shared by every model (`Current(0.00269)`)
+/// or a unit for each exact model when they differ (`<XH430_V350: Current(0.00134), XM430_W350: Current(0.00269)>`).
+///
+/// Each register can have a range of valid values shared by every model (`(0 ~ 1023)`)
+/// or a range for each exact model when they differ (`{XH430_W350: (0 ~ 648), XM430_W350: (0 ~ 1193)}`).
+/// A bound of a range can be a value, a register (`MaxPositionLimit`) or a negated register (`-CurrentLimit`).
+///
+/// Each register can have an initial value shared by every model (`= 0`)
+/// or an initial value for each exact model when they differ (`[XM430_W210 = 330, XM430_W350 = 200]`).
+///
+/// Each register can have the firmware version it was added in (`@ 38`), older firmware doesn't have the register.
+///
+/// The table can be followed by the Indirect blocks of the models, as the address of the first Indirect Address,
+/// the address of the first Indirect Data and the number of registers (`indirect [(168, 224, 20), (578, 634, 8)]`).
 macro_rules! model {
-    (@BASE_MODEL {$($reg:ident : $addr:expr, $len:expr,)+}) => {
+    (@INITIAL $value_type:ident $init:literal) => {
+        Some(Value::$value_type($init))
+    };
+    (@INITIAL $value_type:ident) => {
+        None
+    };
+    (@FIRMWARE $firmware:literal) => {
+        Some($firmware)
+    };
+    (@FIRMWARE) => {
+        None
+    };
+    (@UNIT_OPTION $quantity:ident $scale:literal) => {
+        Some(model!(@UNIT $quantity $scale))
+    };
+    (@UNIT_OPTION) => {
+        None
+    };
+    (@UNIT $quantity:ident $scale:literal) => {
+        Unit {
+            quantity: Quantity::$quantity,
+            scale: $scale,
+        }
+    };
+    (@RANGE_OPTION ($($range:tt)+)) => {
+        Some(model!(@RANGE $($range)+))
+    };
+    (@RANGE_OPTION) => {
+        None
+    };
+    (@RANGE $($range:tt)+) => {
+        model!(@RANGE_MIN [] $($range)+)
+    };
+    (@RANGE_MIN [$($min:tt)+] ~ $($max:tt)+) => {
+        ValueRange {
+            min: model!(@BOUND $($min)+),
+            max: model!(@BOUND $($max)+),
+        }
+    };
+    (@RANGE_MIN [$($min:tt)*] $next:tt $($rest:tt)+) => {
+        model!(@RANGE_MIN [$($min)* $next] $($rest)+)
+    };
+    (@BOUND - $register:ident) => {
+        Bound::NegRegister(Register::$register)
+    };
+    (@BOUND $register:ident) => {
+        Bound::Register(Register::$register)
+    };
+    (@BOUND $value:literal) => {
+        Bound::Value($value)
+    };
+    (@BASE_MODEL [$($model:ident)+] [$($indirect:tt)*] {$($reg:ident : $addr:expr, $len:expr, $value_type:ident, $access:ident, $area:ident $($quantity:ident($scale:literal))? $(<$($unit_model:ident : $unit_quantity:ident($unit_scale:literal)),+>)? $(($($range:tt)+))? $({$($range_model:ident : ($($model_range:tt)+)),+})? $(= $init:literal)? $([$($exact:ident = $exact_init:literal),+])? $(@ $firmware:literal)?,)+}) => {
         pastey::paste!{
-            #[cfg(feature = "std")]
-            pub(crate) static TABLE: std::sync::LazyLock<std::collections::HashMap<Register, RegisterData>> = std::sync::LazyLock::new(|| {
-                [
-                    $(
-                        (Register::$reg, [<BASE_ $reg:snake:upper>]),
-                    )+
-                ].iter().cloned().collect()
-            });
+            pub(crate) static TABLE: $crate::model_macro::RegisterTable = $crate::model_macro::RegisterTable::new(
+                &[$(Register::$reg,)+],
+                &[$([<BASE_ $reg:snake:upper>],)+],
+                &[$(model!(@FIRMWARE $($firmware)?),)+],
+                &[$(model!(@INDIRECT $indirect),)*],
+            );
 
             const fn base_get(register: Register) -> Option<RegisterData> {
                 match register {
@@ -24,26 +93,82 @@ macro_rules! model {
                 }
             }
 
+            fn base_unit(model_or_group: ModelOrModelGroup, register: Register) -> Option<Unit> {
+                let (shared, models): (Option<Unit>, &[(Model, Unit)]) = match register {
+                    $(
+                        Register::$reg => (
+                            model!(@UNIT_OPTION $($quantity $scale)?),
+                            &[$($((Model::$unit_model, model!(@UNIT $unit_quantity $unit_scale)),)+)?],
+                        ),
+                    )+
+                    _ => return None,
+                };
+                $crate::model_macro::resolve_model_value(model_or_group, shared, models)
+            }
+
+            fn base_range(model_or_group: ModelOrModelGroup, register: Register) -> Option<ValueRange> {
+                let (shared, models): (Option<ValueRange>, &[(Model, ValueRange)]) = match register {
+                    $(
+                        Register::$reg => (
+                            model!(@RANGE_OPTION $(($($range)+))?),
+                            &[$($((Model::$range_model, model!(@RANGE $($model_range)+)),)+)?],
+                        ),
+                    )+
+                    _ => return None,
+                };
+                $crate::model_macro::resolve_model_value(model_or_group, shared, models)
+            }
+
+            fn base_initial_value(model_or_group: ModelOrModelGroup, register: Register) -> Option<Value> {
+                let (shared, models): (Option<Value>, &[(Model, Value)]) = match register {
+                    $(
+                        Register::$reg => (
+                            model!(@INITIAL $value_type $($init)?),
+                            &[$($((Model::$exact, Value::$value_type($exact_init)),)+)?],
+                        ),
+                    )+
+                    _ => return None,
+                };
+                $crate::model_macro::resolve_model_value(model_or_group, shared, models)
+            }
+
             $(
                 const [< BASE_ $reg:snake:upper>]: RegisterData =
                     RegisterData {
                         address: $addr,
                         length: $len,
+                        value_type: ValueType::$value_type,
+                        access: Access::$access,
+                        area: Area::$area,
                     };
 
             )+
         }
+
+        model!(@MODELS [$($model)+] [$($reg)+]);
+    };
+    (@INDIRECT ($address:literal, $data:literal, $count:literal)) => {
+        $crate::indirect::IndirectBlock {
+            address: $address,
+            data: $data,
+            count: $count,
+        }
     };
-    (@MODEL $model:ident {$($reg:ident : $addr:expr, $len:expr,)+}) => {
+    (@MODELS [$($model:ident)+] $registers:tt) => {
+        $(
+            model!(@MODEL $model $registers);
+        )+
+    };
+    (@MODEL $model:ident [$($reg:ident)+]) => {
         pastey::paste! {
             #[doc = "The Control Table for the " $model " models."]
+            #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
             pub struct $model;
 
             impl $model {
 
-                #[cfg(feature = "std")]
-                pub(crate) fn table() -> &'static std::collections::HashMap<Register, RegisterData> {
-                    &*TABLE
+                pub(crate) const fn table() -> &'static $crate::model_macro::RegisterTable {
+                    &TABLE
                 }
 
                 #[doc = "return the [`RegisterData`] for this register. Returns an `Option` as the register may not present for this model"]
@@ -51,6 +176,77 @@ macro_rules! model {
                     base_get(register)
                 }
 
+                #[doc = "Iterate over the registers of the " $model " models and their [`RegisterData`], sorted by address"]
+                pub fn registers() -> impl Iterator<Item = (Register, RegisterData)> {
+                    TABLE.iter().map(|(register, data)| (register, *data))
+                }
+
+                #[doc = "return the number of registers of the " $model " models"]
+                pub const fn register_count() -> usize {
+                    TABLE.len()
+                }
+
+                #[doc = "return the Indirect Address and Indirect Data blocks of the " $model " models"]
+                pub const fn indirect_blocks() -> &'static [$crate::indirect::IndirectBlock] {
+                    TABLE.indirect_blocks()
+                }
+
+                #[doc = "return the firmware version this register was added in. Returns `None` if the register is not present or is present in every firmware version"]
+                pub const fn min_firmware(register: Register) -> Option<u8> {
+                    TABLE.min_firmware(register)
+                }
+
+                #[doc = "return the [`Unit`] of this register. Returns `None` if the register is not present, has no unit, or the unit differs between " $model " models"]
+                pub fn unit(register: Register) -> Option<Unit> {
+                    base_unit(ModelGroup::$model.into(), register)
+                }
+
+                #[doc = "return the [`Unit`] of this register for an exact " $model " [`Model`]. Returns `None` if the model is not a " $model " model"]
+                pub fn model_unit(model: Model, register: Register) -> Option<Unit> {
+                    ModelGroup::$model
+                        .contains(model)
+                        .then(|| base_unit(model.into(), register))
+                        .flatten()
+                }
+
+                pub(crate) fn unit_of(model_or_group: ModelOrModelGroup, register: Register) -> Option<Unit> {
+                    base_unit(model_or_group, register)
+                }
+
+                #[doc = "return the range of valid values of this register. Returns `None` if the register is not present, has no range, or the range differs between " $model " models"]
+                pub fn range(register: Register) -> Option<ValueRange> {
+                    base_range(ModelGroup::$model.into(), register)
+                }
+
+                #[doc = "return the range of valid values of this register for an exact " $model " [`Model`]. Returns `None` if the model is not a " $model " model"]
+                pub fn model_range(model: Model, register: Register) -> Option<ValueRange> {
+                    ModelGroup::$model
+                        .contains(model)
+                        .then(|| base_range(model.into(), register))
+                        .flatten()
+                }
+
+                pub(crate) fn range_of(model_or_group: ModelOrModelGroup, register: Register) -> Option<ValueRange> {
+                    base_range(model_or_group, register)
+                }
+
+                #[doc = "return the initial (factory default) value of this register. Returns `None` if the register is not present, has no initial value, or the initial value differs between " $model " models"]
+                pub fn initial_value(register: Register) -> Option<Value> {
+                    base_initial_value(ModelGroup::$model.into(), register)
+                }
+
+                #[doc = "return the initial (factory default) value of this register for an exact " $model " [`Model`]. Returns `None` if the model is not a " $model " model"]
+                pub fn model_initial_value(model: Model, register: Register) -> Option<Value> {
+                    ModelGroup::$model
+                        .contains(model)
+                        .then(|| base_initial_value(model.into(), register))
+                        .flatten()
+                }
+
+                pub(crate) fn initial_value_of(model_or_group: ModelOrModelGroup, register: Register) -> Option<Value> {
+                    base_initial_value(model_or_group, register)
+                }
+
                 $(
                     #[doc = "[`RegisterData`] for [`Register::" $reg "`]"]
                     pub const [<$reg:snake:upper>]: RegisterData = {
@@ -58,18 +254,209 @@ macro_rules! model {
                     };
                 )+
             }
+
+            impl $crate::ModelTable for $model {
+                #[inline]
+                fn model_or_group(&self) -> ModelOrModelGroup {
+                    ModelGroup::$model.into()
+                }
+
+                #[inline]
+                fn get(&self, register: Register) -> Result<&'static RegisterData, $crate::control_table::RegisterError> {
+                    TABLE.get(register).ok_or($crate::control_table::RegisterError::Missing {
+                        model_or_group: ModelGroup::$model.into(),
+                        register,
+                    })
+                }
+
+                #[inline]
+                fn unit(&self, register: Register) -> Result<Option<Unit>, $crate::control_table::RegisterError> {
+                    $crate::ModelTable::get(self, register)?;
+                    Ok(Self::unit(register))
+                }
+
+                #[inline]
+                fn range(&self, register: Register) -> Result<Option<ValueRange>, $crate::control_table::RegisterError> {
+                    $crate::ModelTable::get(self, register)?;
+                    Ok(Self::range(register))
+                }
+
+                #[inline]
+                fn initial_value(&self, register: Register) -> Result<Option<Value>, $crate::control_table::RegisterError> {
+                    $crate::ModelTable::get(self, register)?;
+                    Ok(Self::initial_value(register))
+                }
+
+                #[inline]
+                fn min_firmware(&self, register: Register) -> Result<Option<u8>, $crate::control_table::RegisterError> {
+                    $crate::ModelTable::get(self, register)?;
+                    Ok(Self::min_firmware(register))
+                }
+
+                fn registers(&self) -> impl Iterator<Item = (Register, RegisterData)> {
+                    Self::registers()
+                }
+
+                #[inline]
+                fn register_count(&self) -> usize {
+                    Self::register_count()
+                }
+
+                #[inline]
+                fn indirect_blocks(&self) -> &'static [$crate::indirect::IndirectBlock] {
+                    Self::indirect_blocks()
+                }
+            }
         }
 
     };
-    ($($model:ident)+ => $registers:tt  ) => {
+    ($($model:ident)+ => $registers:tt $(indirect [$($indirect:tt),+ $(,)?])?) => {
         use $crate::RegisterData;
         use $crate::Register;
-        model!(@BASE_MODEL $registers);
-
-        $(
-            model!(@MODEL $model $registers);
-        )+
+        use $crate::Access;
+        use $crate::Area;
+        use $crate::ValueType;
+        use $crate::Value;
+        use $crate::{Bound, ValueRange};
+        use $crate::{Quantity, Unit};
+        use $crate::{Model, ModelGroup, ModelOrModelGroup};
+        model!(@BASE_MODEL [$($model)+] [$($($indirect)+)?] $registers);
     }
 }
 
 pub(crate) use model;
+
+/// The registers of a model group, stored in a dense array indexed by [`Register::index`]
+/// so a lookup is a single array index.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct RegisterTable {
+    /// The registers present sorted by address. Only the first `len` entries are used.
+    registers: [Register; Register::COUNT],
+    len: usize,
+    data: [Option<RegisterData>; Register::COUNT],
+    /// The firmware version each register was added in, `None` if it is in every version
+    firmware: [Option<u8>; Register::COUNT],
+    indirect: &'static [IndirectBlock],
+}
+
+impl RegisterTable {
+    /// Create a table from the registers present, their data and the firmware version they were added in,
+    /// in the same order, and the Indirect blocks.
+    pub(crate) const fn new(
+        registers: &[Register],
+        data: &[RegisterData],
+        firmware: &[Option<u8>],
+        indirect: &'static [IndirectBlock],
+    ) -> Self {
+        assert!(registers.len() == data.len() && registers.len() == firmware.len());
+        let mut table = [None; Register::COUNT];
+        let mut firmware_table = [None; Register::COUNT];
+        let mut sorted = [Register::ModelNumber; Register::COUNT];
+        let mut i = 0;
+        while i < registers.len() {
+            table[registers[i].index()] = Some(data[i]);
+            firmware_table[registers[i].index()] = firmware[i];
+            // insertion sort by address, as iterators are not available in `const fn`
+            let mut j = i;
+            while j > 0 && data[i].address < address_of(&table, sorted[j - 1]) {
+                sorted[j] = sorted[j - 1];
+                j -= 1;
+            }
+            sorted[j] = registers[i];
+            i += 1;
+        }
+        Self {
+            registers: sorted,
+            len: registers.len(),
+            data: table,
+            firmware: firmware_table,
+            indirect,
+        }
+    }
+
+    /// Get the [`RegisterData`] of a register, or `None` if it is not present.
+    pub(crate) const fn get(&self, register: Register) -> Option<&RegisterData> {
+        self.data[register.index()].as_ref()
+    }
+
+    /// The firmware version a register was added in, or `None` if it is in every version or not present
+    pub(crate) const fn min_firmware(&self, register: Register) -> Option<u8> {
+        self.firmware[register.index()]
+    }
+
+    /// The number of registers present
+    pub(crate) const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// The Indirect Address and Indirect Data blocks
+    pub(crate) const fn indirect_blocks(&self) -> &'static [IndirectBlock] {
+        self.indirect
+    }
+
+    /// Iterate over the registers present and their [`RegisterData`], sorted by address
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (Register, &RegisterData)> + '_ {
+        self.registers[..self.len]
+            .iter()
+            .filter_map(|register| Some((*register, self.get(*register)?)))
+    }
+}
+
+const fn address_of(table: &[Option<RegisterData>; Register::COUNT], register: Register) -> u16 {
+    match &table[register.index()] {
+        Some(data) => data.address,
+        None => 0,
+    }
+}
+
+/// Resolve a value that may differ between the models of a model group.
+///
+/// An exact [`Model`] uses its own value if it has one, otherwise the `shared` value.
+/// A [`ModelGroup`](dynamixel_registers::models::ModelGroup) uses the value of its models if they all agree,
+/// counting the models without their own value as using the `shared` value, and `None` if they differ.
+pub(crate) fn resolve_model_value<T: Copy + PartialEq>(
+    model_or_group: ModelOrModelGroup,
+    shared: Option<T>,
+    models: &[(Model, T)],
+) -> Option<T> {
+    match model_or_group {
+        ModelOrModelGroup::Model(model) => models
+            .iter()
+            .find_map(|(m, value)| (*m == model).then_some(*value))
+            .or(shared),
+        ModelOrModelGroup::ModelGroup(model_group) => {
+            let mut values = model_group
+                .models()
+                .map(|model| resolve_model_value(model.into(), shared, models));
+            let first = values.next().unwrap_or(shared);
+            if values.all(|value| value == first) {
+                first
+            } else {
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dynamixel_registers::models::ModelGroup;
+
+    #[test]
+    fn test_resolve_model_value() {
+        let models = [(Model::XM430_W210, 330)];
+        let resolve = |model_or_group| resolve_model_value(model_or_group, Some(200), &models);
+        assert_eq!(resolve(Model::XM430_W210.into()), Some(330));
+        assert_eq!(resolve(Model::XM430_W350.into()), Some(200));
+        // the XM430-W350 uses the shared value, so the group doesn't agree
+        assert_eq!(resolve(ModelGroup::XM430.into()), None);
+        assert_eq!(resolve(ModelGroup::XH430.into()), Some(200));
+
+        let models = [(Model::XM430_W210, 200), (Model::XM430_W350, 200)];
+        assert_eq!(
+            resolve_model_value(ModelGroup::XM430.into(), Some(330), &models),
+            Some(200)
+        );
+    }
+}
@@ -0,0 +1,637 @@
+//! A common interface over the static model structs and the dynamic [`ControlTable`](crate::ControlTable).
+//!
+//! Code written against [`ModelTable`] works with a model known at compile time (e.g. [`XM430`](crate::models::XM430))
+//! or one discovered at runtime. The static model structs are zero sized, so the static case has no overhead.
+
+use core::ops::Range;
+use dynamixel_registers::models::{ModelOrModelGroup, Protocol};
+use dynamixel_registers::{Bound, OperatingMode, Register, RegisterData, Unit, Value, ValueRange};
+
+use crate::bitfield::Bitfield;
+use crate::capabilities::Capabilities;
+use crate::control_table::RegisterError;
+use crate::fault::{ControllerState, ErrorCode, Fault};
+use crate::indirect::IndirectBlock;
+
+/// A register covering an address, returned by [`ModelTable::register_at`] and [`ModelTable::registers_in`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RegisterOffset {
+    /// The register covering the address
+    pub register: Register,
+    /// The [`RegisterData`] of the register
+    pub data: RegisterData,
+    /// The offset of the address within the register, `0` if the address is the start of the register
+    pub offset: u16,
+}
+
+/// A contiguous span of addresses, returned by [`ModelTable::spans`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// The address of the start of the span
+    pub address: u16,
+    /// The number of bytes in the span
+    pub length: u16,
+}
+
+impl Span {
+    /// The address after the end of the span
+    pub const fn end(&self) -> u16 {
+        self.address + self.length
+    }
+}
+
+/// The control table of a model or model group.
+///
+/// Implemented by every static model struct (e.g. [`XM430`](crate::models::XM430)) and by [`ControlTable`](crate::ControlTable).
+pub trait ModelTable {
+    /// Get the [`Model`](crate::Model) if known, otherwise the [`ModelGroup`](crate::ModelGroup) of this table
+    fn model_or_group(&self) -> ModelOrModelGroup;
+
+    /// Get the register data for a specific register.
+    /// Returns [`RegisterError::Missing`] if the model doesn't have the register.
+    fn get(&self, register: Register) -> Result<&'static RegisterData, RegisterError>;
+
+    /// Get the physical unit of a register.
+    /// Returns `None` if the register has no unit, or if there is no exact [`Model`](crate::Model)
+    /// and the unit differs between the models of the [`ModelGroup`](crate::ModelGroup).
+    fn unit(&self, register: Register) -> Result<Option<Unit>, RegisterError>;
+
+    /// Get the range of valid values of a register.
+    /// Returns `None` if the register has no range, or if there is no exact [`Model`](crate::Model)
+    /// and the range differs between the models of the [`ModelGroup`](crate::ModelGroup).
+    fn range(&self, register: Register) -> Result<Option<ValueRange>, RegisterError>;
+
+    /// Get the initial (factory default) value of a register.
+    /// Returns `None` if the register has no initial value, or if there is no exact [`Model`](crate::Model)
+    /// and the initial value differs between the models of the [`ModelGroup`](crate::ModelGroup).
+    fn initial_value(&self, register: Register) -> Result<Option<Value>, RegisterError>;
+
+    /// Get the firmware version a register was added in.
+    /// Returns `None` if the register is present in every firmware version.
+    fn min_firmware(&self, register: Register) -> Result<Option<u8>, RegisterError>;
+
+    /// Iterate over the registers of this table and their [`RegisterData`], sorted by address
+    fn registers(&self) -> impl Iterator<Item = (Register, RegisterData)>;
+
+    /// Get the number of registers of this table
+    fn register_count(&self) -> usize;
+
+    /// Get the Indirect Address and Indirect Data blocks of this table, see [`IndirectMap`](crate::indirect::IndirectMap).
+    /// Empty if the model has no Indirect registers.
+    fn indirect_blocks(&self) -> &'static [IndirectBlock];
+
+    /// Get the version of the protocol the control table is defined for
+    fn protocol(&self) -> Protocol {
+        self.model_or_group().model_group().protocol()
+    }
+
+    /// Get the number of axes of the servo, see [`ModelGroup::axes`](crate::ModelGroup::axes)
+    fn axes(&self) -> u8 {
+        self.model_or_group().model_group().axes()
+    }
+
+    /// Check if a register is shared by both axes of a dual-axis servo, rather than per-axis.
+    /// Returns [`RegisterError::Missing`] if the model doesn't have the register.
+    fn is_shared(&self, register: Register) -> Result<bool, RegisterError> {
+        self.get(register)?;
+        let model_group = self.model_or_group().model_group();
+        Ok(model_group.shared_registers().contains(&register))
+    }
+
+    /// Get the hardware features of the servo, see [`Capabilities`]
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::of(self)
+    }
+
+    /// Get the operating modes supported by the model, see [`ModelGroup::operating_modes`](crate::ModelGroup::operating_modes)
+    fn operating_modes(&self) -> &'static [OperatingMode] {
+        self.model_or_group().model_group().operating_modes()
+    }
+
+    /// Get the value to write to [`Register::OperatingMode`] to switch to `mode`.
+    /// Returns [`RegisterError::UnsupportedMode`] if the model doesn't support the mode.
+    fn operating_mode_value(&self, mode: OperatingMode) -> Result<Value, RegisterError> {
+        self.get_writable(Register::OperatingMode)?;
+        if self.operating_modes().contains(&mode) {
+            Ok(mode.into())
+        } else {
+            Err(RegisterError::UnsupportedMode {
+                model_or_group: self.model_or_group(),
+                mode,
+            })
+        }
+    }
+
+    /// Decode the value read from [`Register::OperatingMode`].
+    /// Returns [`RegisterError::UnsupportedMode`] if the value is a mode the model doesn't support,
+    /// and `None` if it is not a known mode.
+    fn decode_operating_mode(&self, value: u8) -> Result<Option<OperatingMode>, RegisterError> {
+        self.get(Register::OperatingMode)?;
+        let Ok(mode) = OperatingMode::try_from(value) else {
+            return Ok(None);
+        };
+        self.operating_mode_value(mode)?;
+        Ok(Some(mode))
+    }
+
+    /// Decode the raw value of a bitfield register into its flags, see [`Bitfield`].
+    /// Returns `None` if the register is not a bitfield.
+    fn decode_bitfield(
+        &self,
+        register: Register,
+        bits: u8,
+    ) -> Result<Option<Bitfield>, RegisterError> {
+        self.get(register)?;
+        let model_group = self.model_or_group().model_group();
+        Ok(Bitfield::decode(model_group, register, bits))
+    }
+
+    /// Get the registers to read for the faults of the servo, sorted by address, see [`fault`](crate::fault).
+    /// The registers are next to each other, so they can be read as one span (see [`ModelTable::spans`]).
+    /// Empty if the model has no fault register, as on protocol 1.0.
+    fn fault_registers(&self) -> &'static [Register] {
+        if self.get(Register::HardwareErrorStatus).is_ok() {
+            &[Register::HardwareErrorStatus]
+        } else if self.get(Register::ErrorCode).is_ok() {
+            &[Register::ControllerState, Register::ErrorCode]
+        } else {
+            &[]
+        }
+    }
+
+    /// Decode the [`ModelTable::fault_registers`] from `bytes` read from a span starting at `address`.
+    /// Returns [`RegisterError::Missing`] if the model has no fault register,
+    /// [`RegisterError::NotRead`] if `bytes` doesn't cover a fault register,
+    /// and [`RegisterError::UnknownValue`] if a value is not a documented code.
+    fn decode_fault(&self, address: u16, bytes: &[u8]) -> Result<Fault, RegisterError> {
+        let registers = self.fault_registers();
+        let mut values = [0u8; 2];
+        for (register, value) in registers.iter().zip(&mut values) {
+            *value = self
+                .decode_span(address, bytes)
+                .find_map(|(r, value)| (r == *register).then_some(value))
+                .and_then(|value| u8::try_from(value.as_i64()).ok())
+                .ok_or(RegisterError::NotRead {
+                    model_or_group: self.model_or_group(),
+                    register: *register,
+                })?;
+        }
+        let unknown = |register, value| RegisterError::UnknownValue {
+            model_or_group: self.model_or_group(),
+            register,
+            value,
+        };
+        match registers {
+            [Register::ControllerState, Register::ErrorCode] => Ok(Fault::ErrorCode {
+                code: ErrorCode::try_from(values[1]).map_err(|e| unknown(e.register, e.value))?,
+                state: ControllerState::try_from(values[0])
+                    .map_err(|e| unknown(e.register, e.value))?,
+            }),
+            [register, ..] => self
+                .decode_bitfield(*register, values[0])?
+                .map(Fault::Flags)
+                .ok_or(unknown(*register, values[0])),
+            [] => Err(RegisterError::Missing {
+                model_or_group: self.model_or_group(),
+                register: Register::HardwareErrorStatus,
+            }),
+        }
+    }
+
+    /// Get the register data for a register that is going to be written to.
+    /// Returns [`RegisterError::ReadOnly`] if the register cannot be written.
+    fn get_writable(&self, register: Register) -> Result<&'static RegisterData, RegisterError> {
+        let data = self.get(register)?;
+        if data.is_writable() {
+            Ok(data)
+        } else {
+            Err(RegisterError::ReadOnly {
+                model_or_group: self.model_or_group(),
+                register,
+            })
+        }
+    }
+
+    /// Get the register data for a register that is going to be written to, given the current
+    /// state of the [`Register::TorqueEnable`] register.
+    /// Returns [`RegisterError::EepromLocked`] if the register is in the EEPROM area and torque is enabled.
+    fn get_writable_with_torque(
+        &self,
+        register: Register,
+        torque_enabled: bool,
+    ) -> Result<&'static RegisterData, RegisterError> {
+        let data = self.get_writable(register)?;
+        if torque_enabled && data.is_eeprom() {
+            Err(RegisterError::EepromLocked {
+                model_or_group: self.model_or_group(),
+                register,
+            })
+        } else {
+            Ok(data)
+        }
+    }
+
+    /// Get the register covering an address, with the offset of the address within the register.
+    /// Returns `None` if no register covers the address.
+    fn register_at(&self, address: u16) -> Option<RegisterOffset> {
+        self.registers_in(address..address.saturating_add(1)).next()
+    }
+
+    /// Get the registers covering a span of addresses, sorted by address.
+    /// The offset of a register is the offset of the start of the span within it,
+    /// so only the first register can have a non-zero offset.
+    fn registers_in(&self, addresses: Range<u16>) -> impl Iterator<Item = RegisterOffset> {
+        self.registers()
+            .filter(move |(_, data)| {
+                data.address < addresses.end && addresses.start < data.address + data.length
+            })
+            .map(move |(register, data)| RegisterOffset {
+                register,
+                data,
+                offset: addresses.start.saturating_sub(data.address),
+            })
+    }
+
+    /// Get the fewest spans of addresses covering all of `registers`, sorted by address.
+    ///
+    /// Registers separated by up to `max_gap` bytes are covered by the same span, so they can be read together
+    /// at the cost of reading the bytes between them. Use a `max_gap` of `0` to only join registers that are next to each other.
+    /// Returns [`RegisterError::Missing`] if the model doesn't have one of the registers.
+    fn spans<'a>(
+        &'a self,
+        registers: &'a [Register],
+        max_gap: u16,
+    ) -> Result<impl Iterator<Item = Span> + 'a, RegisterError> {
+        for register in registers {
+            self.get(*register)?;
+        }
+        let mut selected = self
+            .registers()
+            .filter(|(register, _)| registers.contains(register))
+            .map(|(_, data)| data)
+            .peekable();
+        Ok(core::iter::from_fn(move || {
+            let first = selected.next()?;
+            let mut end = first.address + first.length;
+            while let Some(next) =
+                selected.next_if(|next| next.address <= end.saturating_add(max_gap))
+            {
+                end = end.max(next.address + next.length);
+            }
+            Some(Span {
+                address: first.address,
+                length: end - first.address,
+            })
+        }))
+    }
+
+    /// Decode the registers contained in `bytes` read from a span starting at `address`.
+    /// Every register entirely within the span is returned, including any in the gaps between the registers requested.
+    fn decode_span<'a>(
+        &'a self,
+        address: u16,
+        bytes: &'a [u8],
+    ) -> impl Iterator<Item = (Register, Value)> + 'a {
+        let end = address.saturating_add(bytes.len() as u16);
+        self.registers_in(address..end)
+            .filter(move |r| r.offset == 0 && r.data.address + r.data.length <= end)
+            .filter_map(move |r| {
+                let start = (r.data.address - address) as usize;
+                let value = r.data.decode(&bytes[start..start + r.data.length as usize]);
+                Some((r.register, value.ok()?))
+            })
+    }
+
+    /// Convert a raw register value to SI units.
+    /// Returns [`RegisterError::NoUnit`] if the register has no unit.
+    fn to_si(&self, register: Register, raw: impl Into<i64>) -> Result<f32, RegisterError> {
+        Ok(unit_or_err(self, register)?.to_si(raw.into()))
+    }
+
+    /// Convert a value in SI units to the nearest raw value of a register.
+    /// Returns [`RegisterError::NoUnit`] if the register has no unit, and
+    /// [`RegisterError::OutOfRange`] if the raw value does not fit in the register's
+    /// [`ValueType`](dynamixel_registers::ValueType).
+    #[allow(clippy::wrong_self_convention)]
+    fn from_si(&self, register: Register, value: f32) -> Result<Value, RegisterError> {
+        let value_type = self.get(register)?.value_type;
+        let raw = unit_or_err(self, register)?.from_si(value);
+        value_type
+            .from_i64(raw)
+            .ok_or_else(|| RegisterError::OutOfRange {
+                model_or_group: self.model_or_group(),
+                register,
+                value: raw,
+                min: value_type.min_value(),
+                max: value_type.max_value(),
+            })
+    }
+
+    /// Check that a value is within the range of valid values of a register.
+    ///
+    /// Bounds that refer to another register (e.g. `-CurrentLimit ~ CurrentLimit`) are resolved to the
+    /// widest value that register can be set to. Use [`ModelTable::validate_with`] to check against the
+    /// present value of those registers.
+    /// If the register has no range, the value is checked against its [`ValueType`](dynamixel_registers::ValueType).
+    ///
+    /// [`Register::GoalPosition`] is only limited by the position limits in the single-turn operating modes.
+    /// As the operating mode is not known, the limits are not checked on models with a multi-turn mode,
+    /// use [`ModelTable::validate_with`] to give the present [`Register::OperatingMode`].
+    ///
+    /// The initial value is always valid, as some registers are disabled by a value outside their range
+    /// (e.g. [`Register::BusWatchdog`] by `0`, [`Register::SecondaryId`] by `255`).
+    fn validate(&self, register: Register, value: impl Into<i64>) -> Result<(), RegisterError> {
+        self.validate_with(register, value, |_| None)
+    }
+
+    /// Check that a value is within the range of valid values of a register,
+    /// using `register_value` to look up the present value of registers the range refers to.
+    /// If `register_value` returns `None` for a register, the widest value that register can be set to is used.
+    fn validate_with(
+        &self,
+        register: Register,
+        value: impl Into<i64>,
+        register_value: impl Fn(Register) -> Option<i64>,
+    ) -> Result<(), RegisterError> {
+        let value = value.into();
+        let data = self.get(register)?;
+        if self
+            .initial_value(register)?
+            .is_some_and(|initial| initial.as_i64() == value)
+        {
+            return Ok(());
+        }
+        let range = match self.range(register)? {
+            Some(_)
+                if register == Register::GoalPosition
+                    && !position_limited(self, &register_value) =>
+            {
+                None
+            }
+            range => range,
+        };
+        let (min, max) = match range {
+            Some(range) => (
+                resolve_bound(self, range.min, true, &register_value),
+                resolve_bound(self, range.max, false, &register_value),
+            ),
+            None => (data.value_type.min_value(), data.value_type.max_value()),
+        };
+        if (min..=max).contains(&value) {
+            Ok(())
+        } else {
+            Err(RegisterError::OutOfRange {
+                model_or_group: self.model_or_group(),
+                register,
+                value,
+                min,
+                max,
+            })
+        }
+    }
+
+    /// Get the factory default byte image of the whole control table, starting at address 0.
+    /// Registers without an initial value, and any gaps between registers, are left as `0`.
+    #[cfg(feature = "std")]
+    fn default_image(&self) -> Vec<u8> {
+        let length = self
+            .registers()
+            .map(|(_, data)| (data.address + data.length) as usize)
+            .max()
+            .unwrap_or_default();
+        let mut image = vec![0; length];
+        for (register, data) in self.registers() {
+            let Ok(Some(value)) = self.initial_value(register) else {
+                continue;
+            };
+            let start = data.address as usize;
+            data.encode(value, &mut image[start..start + data.length as usize])
+                .expect("initial value matches the register");
+        }
+        image
+    }
+}
+
+fn unit_or_err<T: ModelTable + ?Sized>(
+    table: &T,
+    register: Register,
+) -> Result<Unit, RegisterError> {
+    table.unit(register)?.ok_or_else(|| RegisterError::NoUnit {
+        model_or_group: table.model_or_group(),
+        register,
+    })
+}
+
+/// Check if the position limits apply to [`Register::GoalPosition`] in the present operating mode.
+/// They don't in [`OperatingMode::ExtendedPosition`] and [`OperatingMode::CurrentBasedPosition`], which are multi-turn,
+/// so if the operating mode is not known they only apply to models without those modes.
+fn position_limited<T: ModelTable + ?Sized>(
+    table: &T,
+    register_value: impl Fn(Register) -> Option<i64>,
+) -> bool {
+    if !table
+        .operating_modes()
+        .contains(&OperatingMode::ExtendedPosition)
+    {
+        return true;
+    }
+    let mode = register_value(Register::OperatingMode)
+        .and_then(|value| u8::try_from(value).ok())
+        .and_then(|value| OperatingMode::try_from(value).ok());
+    mode.is_some_and(|mode| {
+        !matches!(
+            mode,
+            OperatingMode::ExtendedPosition | OperatingMode::CurrentBasedPosition
+        )
+    })
+}
+
+/// Resolve a [`Bound`] to a value. A bound that refers to a register without a present value
+/// is resolved to the smallest (`is_min`) or largest value that register can be set to.
+fn resolve_bound<T: ModelTable + ?Sized>(
+    table: &T,
+    bound: Bound,
+    is_min: bool,
+    register_value: impl Fn(Register) -> Option<i64>,
+) -> i64 {
+    let widest = |register: Register, is_min: bool| -> i64 {
+        let range = table.range(register).ok().flatten();
+        let bound = range.map(|range| if is_min { range.min } else { range.max });
+        match bound {
+            Some(Bound::Value(value)) => value,
+            _ => {
+                let value_type = table.get(register).map(|data| data.value_type);
+                value_type.map_or(0, |value_type| {
+                    if is_min {
+                        value_type.min_value()
+                    } else {
+                        value_type.max_value()
+                    }
+                })
+            }
+        }
+    };
+    match bound {
+        Bound::Value(value) => value,
+        Bound::Register(register) => {
+            register_value(register).unwrap_or_else(|| widest(register, is_min))
+        }
+        Bound::NegRegister(register) => {
+            -register_value(register).unwrap_or_else(|| widest(register, !is_min))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModelTable;
+    use super::Span;
+    use crate::control_table::RegisterError;
+    use crate::models::XM430;
+    use crate::{ControlTable, Model, ModelGroup, OperatingMode, Register, Value};
+
+    fn goal_position_address(table: &impl ModelTable) -> u16 {
+        table.get(Register::GoalPosition).unwrap().address
+    }
+
+    #[test]
+    fn test_generic() {
+        let control_table = ControlTable::new_with_model(Model::XM430_W350);
+        assert_eq!(goal_position_address(&XM430), 116);
+        assert_eq!(goal_position_address(&control_table), 116);
+
+        assert_eq!(
+            XM430.range(Register::GoalCurrent),
+            control_table.range(Register::GoalCurrent)
+        );
+        assert!(XM430.validate(Register::GoalCurrent, 1193).is_ok());
+        assert_eq!(
+            XM430.get(Register::PresentLoad),
+            Err(crate::control_table::RegisterError::Missing {
+                model_or_group: crate::ModelGroup::XM430.into(),
+                register: Register::PresentLoad,
+            })
+        );
+        assert!(XM430.registers().eq(control_table.registers()));
+    }
+
+    #[test]
+    fn test_dual_axis() {
+        let control_table = ControlTable::new_with_model(Model::_2XL430_W250);
+        assert_eq!(control_table.axes(), 2);
+        assert_eq!(control_table.is_shared(Register::BaudRate), Ok(true));
+        assert_eq!(control_table.is_shared(Register::GoalPosition), Ok(false));
+        assert!(control_table.is_shared(Register::GoalCurrent).is_err());
+        // each axis has the same control table as an XL430
+        assert!(control_table
+            .registers()
+            .eq(crate::models::XL430::registers()));
+
+        assert_eq!(XM430.axes(), 1);
+        assert_eq!(XM430.is_shared(Register::BaudRate), Ok(false));
+    }
+
+    #[test]
+    fn test_operating_mode() {
+        let control_table = ControlTable::new_with_model(Model::XL430_W250);
+        assert_eq!(
+            control_table.operating_mode_value(OperatingMode::Current),
+            Err(RegisterError::UnsupportedMode {
+                model_or_group: Model::XL430_W250.into(),
+                mode: OperatingMode::Current
+            })
+        );
+        assert_eq!(
+            XM430.operating_mode_value(OperatingMode::Current),
+            Ok(Value::U8(0))
+        );
+        assert_eq!(
+            XM430.decode_operating_mode(5),
+            Ok(Some(OperatingMode::CurrentBasedPosition))
+        );
+        assert_eq!(XM430.decode_operating_mode(2), Ok(None));
+        // the controller writes the goal register of the mode
+        let mode = control_table.decode_operating_mode(16).unwrap().unwrap();
+        assert_eq!(
+            control_table.get(mode.goal_register()).unwrap().address,
+            100
+        );
+        assert!(ControlTable::new(ModelGroup::MX28)
+            .operating_mode_value(OperatingMode::Position)
+            .is_err());
+    }
+
+    #[test]
+    fn test_decode_bitfield() {
+        let error = XM430
+            .decode_bitfield(Register::HardwareErrorStatus, 0x30)
+            .unwrap()
+            .unwrap();
+        assert_eq!(error.to_string(), "Electrical Shock Error, Overload Error");
+        assert_eq!(XM430.decode_bitfield(Register::GoalPosition, 0), Ok(None));
+        assert!(ControlTable::new(ModelGroup::YM070)
+            .decode_bitfield(Register::HardwareErrorStatus, 0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_spans() {
+        let registers = [
+            Register::PresentInputVoltage,
+            Register::PresentPwm,
+            Register::PresentCurrent,
+            Register::PresentVelocity,
+            Register::PresentPosition,
+        ];
+        let spans = XM430.spans(&registers, 0).unwrap().collect::<Vec<_>>();
+        assert_eq!(
+            spans,
+            [
+                Span {
+                    address: 124,
+                    length: 12
+                },
+                Span {
+                    address: 144,
+                    length: 2
+                }
+            ]
+        );
+
+        // Velocity Trajectory and Position Trajectory fill the 8 byte gap
+        let spans = XM430.spans(&registers, 8).unwrap().collect::<Vec<_>>();
+        assert_eq!(
+            spans,
+            [Span {
+                address: 124,
+                length: 22
+            }]
+        );
+
+        assert!(XM430.spans(&[Register::PresentLoad], 0).is_err());
+    }
+
+    #[test]
+    fn test_decode_span() {
+        let mut bytes = [0; 22];
+        bytes[0..2].copy_from_slice(&(-885i16).to_le_bytes());
+        bytes[8..12].copy_from_slice(&4095i32.to_le_bytes());
+        bytes[20..22].copy_from_slice(&120u16.to_le_bytes());
+        let values = XM430.decode_span(124, &bytes).collect::<Vec<_>>();
+        assert_eq!(values.len(), 7);
+        assert_eq!(values[0], (Register::PresentPwm, Value::I16(-885)));
+        assert!(values.contains(&(Register::PresentPosition, Value::I32(4095))));
+        assert_eq!(values[6], (Register::PresentInputVoltage, Value::U16(120)));
+
+        // registers cut off at either end of the span are skipped
+        let values = XM430.decode_span(126, &bytes[2..12]).collect::<Vec<_>>();
+        assert_eq!(
+            values.iter().map(|(r, _)| *r).collect::<Vec<_>>(),
+            [
+                Register::PresentCurrent,
+                Register::PresentVelocity,
+                Register::PresentPosition
+            ]
+        );
+    }
+}
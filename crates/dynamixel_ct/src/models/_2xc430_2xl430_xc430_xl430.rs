@@ -0,0 +1,57 @@
+//! Dynamixel _2XC430 _2XL430 XC430 XL430 model definitions.
+
+use crate::model;
+
+model![_2XC430 _2XL430 XC430 XL430 => {
+    ModelNumber: 0, 2, U16, Read, Eeprom [XC430_W150 = 1070, XC430_W240 = 1080, XL430_W250 = 1060, _2XL430_W250 = 1090, _2XC430_W250 = 1160],
+    ModelInformation: 2, 4, U32, Read, Eeprom,
+    FirmwareVersion: 6, 1, U8, Read, Eeprom,
+    Id: 7, 1, U8, ReadWrite, Eeprom (0 ~ 252) = 1,
+    BaudRate: 8, 1, U8, ReadWrite, Eeprom (0 ~ 7) = 1,
+    ReturnDelayTime: 9, 1, U8, ReadWrite, Eeprom Time(2e-06) (0 ~ 254) = 250,
+    DriveMode: 10, 1, U8, ReadWrite, Eeprom (0 ~ 13) = 0,
+    OperatingMode: 11, 1, U8, ReadWrite, Eeprom (0 ~ 16) = 3,
+    SecondaryId: 12, 1, U8, ReadWrite, Eeprom (0 ~ 252) = 255,
+    HomingOffset: 20, 4, I32, ReadWrite, Eeprom Angle(0.00153398) (-1044479 ~ 1044479) = 0,
+    MovingThreshold: 24, 4, U32, ReadWrite, Eeprom AngularVelocity(0.0239808) (0 ~ 1023) = 10,
+    TemperatureLimit: 31, 1, U8, ReadWrite, Eeprom Temperature(1.0) (0 ~ 100) = 72,
+    MaxVoltageLimit: 32, 2, U16, ReadWrite, Eeprom Voltage(0.1) (60 ~ 140) = 140,
+    MinVoltageLimit: 34, 2, U16, ReadWrite, Eeprom Voltage(0.1) (60 ~ 140) = 60,
+    PwmLimit: 36, 2, U16, ReadWrite, Eeprom Ratio(0.00113) (0 ~ 885) = 885,
+    VelocityLimit: 44, 4, U32, ReadWrite, Eeprom AngularVelocity(0.0239808) (0 ~ 1023) [XC430_W150 = 415, XC430_W240 = 306, XL430_W250 = 265, _2XL430_W250 = 265, _2XC430_W250 = 265],
+    MaxPositionLimit: 48, 4, U32, ReadWrite, Eeprom Angle(0.00153398) (0 ~ 4095) = 4095,
+    MinPositionLimit: 52, 4, U32, ReadWrite, Eeprom Angle(0.00153398) (0 ~ 4095) = 0,
+    StartupConfiguration: 60, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0 @ 45,
+    Shutdown: 63, 1, U8, ReadWrite, Eeprom = 52,
+    TorqueEnable: 64, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    Led: 65, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    StatusReturnLevel: 68, 1, U8, ReadWrite, Ram (0 ~ 2) = 2,
+    RegisteredInstruction: 69, 1, U8, Read, Ram (0 ~ 1) = 0,
+    HardwareErrorStatus: 70, 1, U8, Read, Ram = 0,
+    VelocityIGain: 76, 2, U16, ReadWrite, Ram (0 ~ 16383) = 1000,
+    VelocityPGain: 78, 2, U16, ReadWrite, Ram (0 ~ 16383) = 100,
+    PositionPGain: 84, 2, U16, ReadWrite, Ram (0 ~ 16383) = 640,
+    BusWatchdog: 98, 1, U8, ReadWrite, Ram Time(0.02) (1 ~ 127) = 0 @ 38,
+    GoalPwm: 100, 2, I16, ReadWrite, Ram Ratio(0.00113) (-PwmLimit ~ PwmLimit),
+    GoalVelocity: 104, 4, I32, ReadWrite, Ram AngularVelocity(0.0239808) (-VelocityLimit ~ VelocityLimit),
+    ProfileAcceleration: 108, 4, U32, ReadWrite, Ram AngularAcceleration(0.374508) (0 ~ 32767) = 0,
+    ProfileVelocity: 112, 4, U32, ReadWrite, Ram AngularVelocity(0.0239808) (0 ~ 32767) = 0,
+    GoalPosition: 116, 4, I32, ReadWrite, Ram Angle(0.00153398) (MinPositionLimit ~ MaxPositionLimit),
+    Moving: 122, 1, U8, Read, Ram (0 ~ 1) = 0,
+    MovingStatus: 123, 1, U8, Read, Ram = 0,
+    PresentPwm: 124, 2, I16, Read, Ram Ratio(0.00113),
+    PresentVelocity: 128, 4, I32, Read, Ram AngularVelocity(0.0239808),
+    PresentPosition: 132, 4, I32, Read, Ram Angle(0.00153398),
+    VelocityTrajectory: 136, 4, I32, Read, Ram AngularVelocity(0.0239808),
+    PositionTrajectory: 140, 4, I32, Read, Ram Angle(0.00153398),
+    PresentInputVoltage: 144, 2, U16, Read, Ram Voltage(0.1),
+    PresentTemperature: 146, 1, U8, Read, Ram Temperature(1.0),
+    BackupReady: 147, 1, U8, Read, Ram (0 ~ 1) = 0 @ 45,
+    PositionDGain: 80, 2, U16, ReadWrite, Ram (0 ~ 16383) = 4000,
+    PositionIGain: 82, 2, U16, ReadWrite, Ram (0 ~ 16383) = 0,
+    ProtocolType: 13, 1, U8, ReadWrite, Eeprom (1 ~ 2) = 2,
+    Feedforward2NdGain: 88, 2, U16, ReadWrite, Ram (0 ~ 16383) = 0,
+    Feedforward1StGain: 90, 2, U16, ReadWrite, Ram (0 ~ 16383) = 0,
+    RealtimeTick: 120, 2, U16, Read, Ram Time(0.001) (0 ~ 32767),
+    PresentLoad: 126, 2, I16, Read, Ram Ratio(0.001),
+} indirect [(168, 224, 20), (578, 634, 8)]];
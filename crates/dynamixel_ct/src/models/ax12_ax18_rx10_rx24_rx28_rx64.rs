@@ -0,0 +1,38 @@
+//! Dynamixel AX12 AX18 RX10 RX24 RX28 RX64 model definitions.
+
+use crate::model;
+
+model![AX12 AX18 RX10 RX24 RX28 RX64 => {
+    ModelNumber: 0, 2, U16, Read, Eeprom [AX_12A = 12, AX_12W = 300, AX_18A = 18, RX_10 = 10, RX_24F = 24, RX_28 = 28, RX_64 = 64],
+    FirmwareVersion: 2, 1, U8, Read, Eeprom,
+    Id: 3, 1, U8, ReadWrite, Eeprom (0 ~ 253) = 1,
+    BaudRate: 4, 1, U8, ReadWrite, Eeprom (0 ~ 254) [AX_12A = 1, AX_12W = 1, AX_18A = 1, RX_10 = 34, RX_24F = 1, RX_28 = 34, RX_64 = 34],
+    ReturnDelayTime: 5, 1, U8, ReadWrite, Eeprom Time(2e-06) (0 ~ 254) = 250,
+    CwAngleLimit: 6, 2, U16, ReadWrite, Eeprom Angle(0.00506145) (0 ~ 1023) = 0,
+    CcwAngleLimit: 8, 2, U16, ReadWrite, Eeprom Angle(0.00506145) (0 ~ 1023) = 1023,
+    TemperatureLimit: 11, 1, U8, ReadWrite, Eeprom Temperature(1.0) (0 ~ 99) [AX_12A = 70, AX_12W = 70, AX_18A = 75, RX_10 = 80, RX_24F = 80, RX_28 = 80, RX_64 = 80],
+    MinVoltageLimit: 12, 1, U8, ReadWrite, Eeprom Voltage(0.1) (50 ~ 250) = 60,
+    MaxVoltageLimit: 13, 1, U8, ReadWrite, Eeprom Voltage(0.1) (50 ~ 250) [AX_12A = 140, AX_12W = 140, AX_18A = 140, RX_10 = 140, RX_24F = 160, RX_28 = 190, RX_64 = 190],
+    MaxTorque: 14, 2, U16, ReadWrite, Eeprom (0 ~ 1023) = 1023,
+    StatusReturnLevel: 16, 1, U8, ReadWrite, Eeprom (0 ~ 2) = 2,
+    AlarmLed: 17, 1, U8, ReadWrite, Eeprom = 36,
+    Shutdown: 18, 1, U8, ReadWrite, Eeprom = 36,
+    TorqueEnable: 24, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    Led: 25, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    CwComplianceMargin: 26, 1, U8, ReadWrite, Ram (0 ~ 255) = 1,
+    CcwComplianceMargin: 27, 1, U8, ReadWrite, Ram (0 ~ 255) = 1,
+    CwComplianceSlope: 28, 1, U8, ReadWrite, Ram (1 ~ 254) = 32,
+    CcwComplianceSlope: 29, 1, U8, ReadWrite, Ram (1 ~ 254) = 32,
+    GoalPosition: 30, 2, U16, ReadWrite, Ram Angle(0.00506145) (0 ~ 1023),
+    MovingSpeed: 32, 2, U16, ReadWrite, Ram (0 ~ 2047),
+    TorqueLimit: 34, 2, U16, ReadWrite, Ram (0 ~ 1023),
+    PresentPosition: 36, 2, U16, Read, Ram Angle(0.00506145),
+    PresentSpeed: 38, 2, U16, Read, Ram,
+    PresentLoad: 40, 2, U16, Read, Ram,
+    PresentVoltage: 42, 1, U8, Read, Ram Voltage(0.1),
+    PresentTemperature: 43, 1, U8, Read, Ram Temperature(1.0),
+    Registered: 44, 1, U8, Read, Ram (0 ~ 1) = 0,
+    Moving: 46, 1, U8, Read, Ram (0 ~ 1) = 0,
+    Lock: 47, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    Punch: 48, 2, U16, ReadWrite, Ram (0 ~ 1023) = 32,
+}];
@@ -0,0 +1,40 @@
+//! Dynamixel EX106 model definitions.
+
+use crate::model;
+
+model![EX106 => {
+    ModelNumber: 0, 2, U16, Read, Eeprom = 107,
+    FirmwareVersion: 2, 1, U8, Read, Eeprom,
+    Id: 3, 1, U8, ReadWrite, Eeprom (0 ~ 253) = 1,
+    BaudRate: 4, 1, U8, ReadWrite, Eeprom (0 ~ 254) = 34,
+    ReturnDelayTime: 5, 1, U8, ReadWrite, Eeprom Time(2e-06) (0 ~ 254) = 250,
+    CwAngleLimit: 6, 2, U16, ReadWrite, Eeprom Angle(0.0010472) (0 ~ 4095) = 0,
+    CcwAngleLimit: 8, 2, U16, ReadWrite, Eeprom Angle(0.0010472) (0 ~ 4095) = 4095,
+    DriveMode: 10, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0,
+    TemperatureLimit: 11, 1, U8, ReadWrite, Eeprom Temperature(1.0) (0 ~ 99) = 80,
+    MinVoltageLimit: 12, 1, U8, ReadWrite, Eeprom Voltage(0.1) (50 ~ 250) = 60,
+    MaxVoltageLimit: 13, 1, U8, ReadWrite, Eeprom Voltage(0.1) (50 ~ 250) = 160,
+    MaxTorque: 14, 2, U16, ReadWrite, Eeprom (0 ~ 1023) = 1023,
+    StatusReturnLevel: 16, 1, U8, ReadWrite, Eeprom (0 ~ 2) = 2,
+    AlarmLed: 17, 1, U8, ReadWrite, Eeprom = 36,
+    Shutdown: 18, 1, U8, ReadWrite, Eeprom = 36,
+    TorqueEnable: 24, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    Led: 25, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    CwComplianceMargin: 26, 1, U8, ReadWrite, Ram (0 ~ 255) = 1,
+    CcwComplianceMargin: 27, 1, U8, ReadWrite, Ram (0 ~ 255) = 1,
+    CwComplianceSlope: 28, 1, U8, ReadWrite, Ram (1 ~ 254) = 32,
+    CcwComplianceSlope: 29, 1, U8, ReadWrite, Ram (1 ~ 254) = 32,
+    GoalPosition: 30, 2, U16, ReadWrite, Ram Angle(0.0010472) (0 ~ 4095),
+    MovingSpeed: 32, 2, U16, ReadWrite, Ram (0 ~ 2047),
+    TorqueLimit: 34, 2, U16, ReadWrite, Ram (0 ~ 1023),
+    PresentPosition: 36, 2, U16, Read, Ram Angle(0.0010472),
+    PresentSpeed: 38, 2, U16, Read, Ram,
+    PresentLoad: 40, 2, U16, Read, Ram,
+    PresentVoltage: 42, 1, U8, Read, Ram Voltage(0.1),
+    PresentTemperature: 43, 1, U8, Read, Ram Temperature(1.0),
+    Registered: 44, 1, U8, Read, Ram (0 ~ 1) = 0,
+    Moving: 46, 1, U8, Read, Ram (0 ~ 1) = 0,
+    Lock: 47, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    Punch: 48, 2, U16, ReadWrite, Ram (0 ~ 1023) = 32,
+    SensedCurrent: 56, 2, U16, Read, Ram,
+}];
@@ -7,56 +7,81 @@ mod xd540_xh540_xm540;
 pub use xd540_xh540_xm540::*;
 mod xd430_xh430_xm430;
 pub use xd430_xh430_xm430::*;
-mod xw540;
-pub use xw540::*;
-mod xc430_xl430;
-pub use xc430_xl430::*;
+mod xw430_xw540;
+pub use xw430_xw540::*;
+mod _2xc430_2xl430_xc430_xl430;
+pub use _2xc430_2xl430_xc430_xl430::*;
 mod ym070_ym080;
 pub use ym070_ym080::*;
 mod ph42_ph54_pm42_pm54;
 pub use ph42_ph54_pm42_pm54::*;
+mod ax12_ax18_rx10_rx24_rx28_rx64;
+pub use ax12_ax18_rx10_rx24_rx28_rx64::*;
+mod ex106;
+pub use ex106::*;
+mod mx12_mx28;
+pub use mx12_mx28::*;
+mod mx64;
+pub use mx64::*;
+mod mx106;
+pub use mx106::*;
+mod xl320;
+pub use xl320::*;
+mod rhp12rn;
+pub use rhp12rn::*;
+mod rhp12rna;
+pub use rhp12rna::*;
 
 #[expect(unused)]
-#[cfg(feature = "std")]
 pub(crate) fn control_table_from_model(
     model: &dynamixel_registers::models::Model,
-) -> &'static std::collections::HashMap<
-    dynamixel_registers::Register,
-    dynamixel_registers::RegisterData,
-> {
+) -> &'static crate::model_macro::RegisterTable {
     use dynamixel_registers::models::Model::*;
     match model {
-        XC330_T181 | XC330_T288 | XC330_M181 | XC330_M288 => XC330::table(),
-        XL330_M077 | XL330_M288 => XL330::table(),
-        XM335_T323 => XM335::table(),
-        XD540_T270 | XD540_T150 => XD540::table(),
-        XH540_W270 | XH540_W150 | XH540_V270 | XH540_V150 => XH540::table(),
-        XM540_W270 | XM540_W150 => XM540::table(),
-        XD430_T350 | XD430_T210 => XD430::table(),
-        XH430_W350 | XH430_W210 | XH430_V350 | XH430_V210 => XH430::table(),
-        XM430_W350 | XM430_W210 => XM430::table(),
-        XW540_T260 | XW540_T140 | XW540_H260 => XW540::table(),
-        XC430_W150 | XC430_W240 => XC430::table(),
-        XL430_W250 => XL430::table(),
+        XC330_T181 | XC330_T288 | XC330_M181 | XC330_M288 => self::XC330::table(),
+        XL330_M077 | XL330_M288 => self::XL330::table(),
+        XM335_T323 => self::XM335::table(),
+        XD540_T270 | XD540_T150 => self::XD540::table(),
+        XH540_W270 | XH540_W150 | XH540_V270 | XH540_V150 => self::XH540::table(),
+        XM540_W270 | XM540_W150 => self::XM540::table(),
+        XD430_T350 | XD430_T210 => self::XD430::table(),
+        XH430_W350 | XH430_W210 | XH430_V350 | XH430_V210 => self::XH430::table(),
+        XM430_W350 | XM430_W210 => self::XM430::table(),
+        XW430_T333 | XW430_T200 => self::XW430::table(),
+        XW540_T260 | XW540_T140 | XW540_H260 => self::XW540::table(),
+        _2XL430_W250 => self::_2XL430::table(),
+        _2XC430_W250 => self::_2XC430::table(),
+        XC430_W150 | XC430_W240 => self::XC430::table(),
+        XL430_W250 => self::XL430::table(),
         YM070_210_M001_RH | YM070_210_B001_RH | YM070_200_R051_RH | YM070_200_R099_RH
-        | YM070_210_A051_RH | YM070_200_A099_RH => YM070::table(),
+        | YM070_210_A051_RH | YM070_200_A099_RH => self::YM070::table(),
         YM080_230_M001_RH | YM080_230_B001_RH | YM080_230_R051_RH | YM080_230_R099_RH
-        | YM080_230_A051_RH | YM080_230_A099_RH => YM080::table(),
-        PH42_020_S300_R => PH42::table(),
-        PH54_100_S500_R | PH54_200_S500_R => PH54::table(),
-        PM42_010_S260_R => PM42::table(),
-        PM54_040_S250_R | PM54_060_S250_R => PM54::table(),
+        | YM080_230_A051_RH | YM080_230_A099_RH => self::YM080::table(),
+        PH42_020_S300_R => self::PH42::table(),
+        PH54_100_S500_R | PH54_200_S500_R => self::PH54::table(),
+        PM42_010_S260_R => self::PM42::table(),
+        PM54_040_S250_R | PM54_060_S250_R => self::PM54::table(),
+        AX_12A | AX_12W => self::AX12::table(),
+        AX_18A => self::AX18::table(),
+        RX_10 => self::RX10::table(),
+        RX_24F => self::RX24::table(),
+        RX_28 => self::RX28::table(),
+        RX_64 => self::RX64::table(),
+        EX_106 => self::EX106::table(),
+        MX_12W => self::MX12::table(),
+        MX_28 => self::MX28::table(),
+        MX_64 => self::MX64::table(),
+        MX_106 => self::MX106::table(),
+        XL320 => self::XL320::table(),
+        RH_P12_RN => self::RHP12RN::table(),
+        RH_P12_RNA => self::RHP12RNA::table(),
         _ => panic!("unknown model"),
     }
 }
 
-#[cfg(feature = "std")]
 pub(crate) fn control_table_from_model_group(
     model_group: &dynamixel_registers::models::ModelGroup,
-) -> &'static std::collections::HashMap<
-    dynamixel_registers::Register,
-    dynamixel_registers::RegisterData,
-> {
+) -> &'static crate::model_macro::RegisterTable {
     use dynamixel_registers::models::ModelGroup;
     match model_group {
         ModelGroup::XC330 => XC330::table(),
@@ -68,7 +93,10 @@ pub(crate) fn control_table_from_model_group(
         ModelGroup::XD430 => XD430::table(),
         ModelGroup::XH430 => XH430::table(),
         ModelGroup::XM430 => XM430::table(),
+        ModelGroup::XW430 => XW430::table(),
         ModelGroup::XW540 => XW540::table(),
+        ModelGroup::_2XC430 => _2XC430::table(),
+        ModelGroup::_2XL430 => _2XL430::table(),
         ModelGroup::XC430 => XC430::table(),
         ModelGroup::XL430 => XL430::table(),
         ModelGroup::YM070 => YM070::table(),
@@ -77,6 +105,155 @@ pub(crate) fn control_table_from_model_group(
         ModelGroup::PH54 => PH54::table(),
         ModelGroup::PM42 => PM42::table(),
         ModelGroup::PM54 => PM54::table(),
+        ModelGroup::AX12 => AX12::table(),
+        ModelGroup::AX18 => AX18::table(),
+        ModelGroup::RX10 => RX10::table(),
+        ModelGroup::RX24 => RX24::table(),
+        ModelGroup::RX28 => RX28::table(),
+        ModelGroup::RX64 => RX64::table(),
+        ModelGroup::EX106 => EX106::table(),
+        ModelGroup::MX12 => MX12::table(),
+        ModelGroup::MX28 => MX28::table(),
+        ModelGroup::MX64 => MX64::table(),
+        ModelGroup::MX106 => MX106::table(),
+        ModelGroup::XL320 => XL320::table(),
+        ModelGroup::RHP12RN => RHP12RN::table(),
+        ModelGroup::RHP12RNA => RHP12RNA::table(),
+        _ => panic!("unknown model group"),
+    }
+}
+
+pub(crate) fn range_from_model_or_group(
+    model_or_group: dynamixel_registers::models::ModelOrModelGroup,
+    register: dynamixel_registers::Register,
+) -> Option<dynamixel_registers::ValueRange> {
+    use dynamixel_registers::models::ModelGroup;
+    match model_or_group.model_group() {
+        ModelGroup::XC330 => XC330::range_of(model_or_group, register),
+        ModelGroup::XL330 => XL330::range_of(model_or_group, register),
+        ModelGroup::XM335 => XM335::range_of(model_or_group, register),
+        ModelGroup::XD540 => XD540::range_of(model_or_group, register),
+        ModelGroup::XH540 => XH540::range_of(model_or_group, register),
+        ModelGroup::XM540 => XM540::range_of(model_or_group, register),
+        ModelGroup::XD430 => XD430::range_of(model_or_group, register),
+        ModelGroup::XH430 => XH430::range_of(model_or_group, register),
+        ModelGroup::XM430 => XM430::range_of(model_or_group, register),
+        ModelGroup::XW430 => XW430::range_of(model_or_group, register),
+        ModelGroup::XW540 => XW540::range_of(model_or_group, register),
+        ModelGroup::_2XC430 => _2XC430::range_of(model_or_group, register),
+        ModelGroup::_2XL430 => _2XL430::range_of(model_or_group, register),
+        ModelGroup::XC430 => XC430::range_of(model_or_group, register),
+        ModelGroup::XL430 => XL430::range_of(model_or_group, register),
+        ModelGroup::YM070 => YM070::range_of(model_or_group, register),
+        ModelGroup::YM080 => YM080::range_of(model_or_group, register),
+        ModelGroup::PH42 => PH42::range_of(model_or_group, register),
+        ModelGroup::PH54 => PH54::range_of(model_or_group, register),
+        ModelGroup::PM42 => PM42::range_of(model_or_group, register),
+        ModelGroup::PM54 => PM54::range_of(model_or_group, register),
+        ModelGroup::AX12 => AX12::range_of(model_or_group, register),
+        ModelGroup::AX18 => AX18::range_of(model_or_group, register),
+        ModelGroup::RX10 => RX10::range_of(model_or_group, register),
+        ModelGroup::RX24 => RX24::range_of(model_or_group, register),
+        ModelGroup::RX28 => RX28::range_of(model_or_group, register),
+        ModelGroup::RX64 => RX64::range_of(model_or_group, register),
+        ModelGroup::EX106 => EX106::range_of(model_or_group, register),
+        ModelGroup::MX12 => MX12::range_of(model_or_group, register),
+        ModelGroup::MX28 => MX28::range_of(model_or_group, register),
+        ModelGroup::MX64 => MX64::range_of(model_or_group, register),
+        ModelGroup::MX106 => MX106::range_of(model_or_group, register),
+        ModelGroup::XL320 => XL320::range_of(model_or_group, register),
+        ModelGroup::RHP12RN => RHP12RN::range_of(model_or_group, register),
+        ModelGroup::RHP12RNA => RHP12RNA::range_of(model_or_group, register),
+        _ => panic!("unknown model group"),
+    }
+}
+
+pub(crate) fn unit_from_model_or_group(
+    model_or_group: dynamixel_registers::models::ModelOrModelGroup,
+    register: dynamixel_registers::Register,
+) -> Option<dynamixel_registers::Unit> {
+    use dynamixel_registers::models::ModelGroup;
+    match model_or_group.model_group() {
+        ModelGroup::XC330 => XC330::unit_of(model_or_group, register),
+        ModelGroup::XL330 => XL330::unit_of(model_or_group, register),
+        ModelGroup::XM335 => XM335::unit_of(model_or_group, register),
+        ModelGroup::XD540 => XD540::unit_of(model_or_group, register),
+        ModelGroup::XH540 => XH540::unit_of(model_or_group, register),
+        ModelGroup::XM540 => XM540::unit_of(model_or_group, register),
+        ModelGroup::XD430 => XD430::unit_of(model_or_group, register),
+        ModelGroup::XH430 => XH430::unit_of(model_or_group, register),
+        ModelGroup::XM430 => XM430::unit_of(model_or_group, register),
+        ModelGroup::XW430 => XW430::unit_of(model_or_group, register),
+        ModelGroup::XW540 => XW540::unit_of(model_or_group, register),
+        ModelGroup::_2XC430 => _2XC430::unit_of(model_or_group, register),
+        ModelGroup::_2XL430 => _2XL430::unit_of(model_or_group, register),
+        ModelGroup::XC430 => XC430::unit_of(model_or_group, register),
+        ModelGroup::XL430 => XL430::unit_of(model_or_group, register),
+        ModelGroup::YM070 => YM070::unit_of(model_or_group, register),
+        ModelGroup::YM080 => YM080::unit_of(model_or_group, register),
+        ModelGroup::PH42 => PH42::unit_of(model_or_group, register),
+        ModelGroup::PH54 => PH54::unit_of(model_or_group, register),
+        ModelGroup::PM42 => PM42::unit_of(model_or_group, register),
+        ModelGroup::PM54 => PM54::unit_of(model_or_group, register),
+        ModelGroup::AX12 => AX12::unit_of(model_or_group, register),
+        ModelGroup::AX18 => AX18::unit_of(model_or_group, register),
+        ModelGroup::RX10 => RX10::unit_of(model_or_group, register),
+        ModelGroup::RX24 => RX24::unit_of(model_or_group, register),
+        ModelGroup::RX28 => RX28::unit_of(model_or_group, register),
+        ModelGroup::RX64 => RX64::unit_of(model_or_group, register),
+        ModelGroup::EX106 => EX106::unit_of(model_or_group, register),
+        ModelGroup::MX12 => MX12::unit_of(model_or_group, register),
+        ModelGroup::MX28 => MX28::unit_of(model_or_group, register),
+        ModelGroup::MX64 => MX64::unit_of(model_or_group, register),
+        ModelGroup::MX106 => MX106::unit_of(model_or_group, register),
+        ModelGroup::XL320 => XL320::unit_of(model_or_group, register),
+        ModelGroup::RHP12RN => RHP12RN::unit_of(model_or_group, register),
+        ModelGroup::RHP12RNA => RHP12RNA::unit_of(model_or_group, register),
+        _ => panic!("unknown model group"),
+    }
+}
+
+pub(crate) fn initial_value_from_model_or_group(
+    model_or_group: dynamixel_registers::models::ModelOrModelGroup,
+    register: dynamixel_registers::Register,
+) -> Option<dynamixel_registers::Value> {
+    use dynamixel_registers::models::ModelGroup;
+    match model_or_group.model_group() {
+        ModelGroup::XC330 => XC330::initial_value_of(model_or_group, register),
+        ModelGroup::XL330 => XL330::initial_value_of(model_or_group, register),
+        ModelGroup::XM335 => XM335::initial_value_of(model_or_group, register),
+        ModelGroup::XD540 => XD540::initial_value_of(model_or_group, register),
+        ModelGroup::XH540 => XH540::initial_value_of(model_or_group, register),
+        ModelGroup::XM540 => XM540::initial_value_of(model_or_group, register),
+        ModelGroup::XD430 => XD430::initial_value_of(model_or_group, register),
+        ModelGroup::XH430 => XH430::initial_value_of(model_or_group, register),
+        ModelGroup::XM430 => XM430::initial_value_of(model_or_group, register),
+        ModelGroup::XW430 => XW430::initial_value_of(model_or_group, register),
+        ModelGroup::XW540 => XW540::initial_value_of(model_or_group, register),
+        ModelGroup::_2XC430 => _2XC430::initial_value_of(model_or_group, register),
+        ModelGroup::_2XL430 => _2XL430::initial_value_of(model_or_group, register),
+        ModelGroup::XC430 => XC430::initial_value_of(model_or_group, register),
+        ModelGroup::XL430 => XL430::initial_value_of(model_or_group, register),
+        ModelGroup::YM070 => YM070::initial_value_of(model_or_group, register),
+        ModelGroup::YM080 => YM080::initial_value_of(model_or_group, register),
+        ModelGroup::PH42 => PH42::initial_value_of(model_or_group, register),
+        ModelGroup::PH54 => PH54::initial_value_of(model_or_group, register),
+        ModelGroup::PM42 => PM42::initial_value_of(model_or_group, register),
+        ModelGroup::PM54 => PM54::initial_value_of(model_or_group, register),
+        ModelGroup::AX12 => AX12::initial_value_of(model_or_group, register),
+        ModelGroup::AX18 => AX18::initial_value_of(model_or_group, register),
+        ModelGroup::RX10 => RX10::initial_value_of(model_or_group, register),
+        ModelGroup::RX24 => RX24::initial_value_of(model_or_group, register),
+        ModelGroup::RX28 => RX28::initial_value_of(model_or_group, register),
+        ModelGroup::RX64 => RX64::initial_value_of(model_or_group, register),
+        ModelGroup::EX106 => EX106::initial_value_of(model_or_group, register),
+        ModelGroup::MX12 => MX12::initial_value_of(model_or_group, register),
+        ModelGroup::MX28 => MX28::initial_value_of(model_or_group, register),
+        ModelGroup::MX64 => MX64::initial_value_of(model_or_group, register),
+        ModelGroup::MX106 => MX106::initial_value_of(model_or_group, register),
+        ModelGroup::XL320 => XL320::initial_value_of(model_or_group, register),
+        ModelGroup::RHP12RN => RHP12RN::initial_value_of(model_or_group, register),
+        ModelGroup::RHP12RNA => RHP12RNA::initial_value_of(model_or_group, register),
         _ => panic!("unknown model group"),
     }
 }
@@ -0,0 +1,41 @@
+//! Dynamixel MX12 MX28 model definitions.
+
+use crate::model;
+
+model![MX12 MX28 => {
+    ModelNumber: 0, 2, U16, Read, Eeprom [MX_12W = 360, MX_28 = 29],
+    FirmwareVersion: 2, 1, U8, Read, Eeprom,
+    Id: 3, 1, U8, ReadWrite, Eeprom (0 ~ 253) = 1,
+    BaudRate: 4, 1, U8, ReadWrite, Eeprom (0 ~ 254) [MX_12W = 1, MX_28 = 34],
+    ReturnDelayTime: 5, 1, U8, ReadWrite, Eeprom Time(2e-06) (0 ~ 254) = 250,
+    CwAngleLimit: 6, 2, U16, ReadWrite, Eeprom Angle(0.00153589) (0 ~ 4095) = 0,
+    CcwAngleLimit: 8, 2, U16, ReadWrite, Eeprom Angle(0.00153589) (0 ~ 4095) = 4095,
+    TemperatureLimit: 11, 1, U8, ReadWrite, Eeprom Temperature(1.0) (0 ~ 99) = 80,
+    MinVoltageLimit: 12, 1, U8, ReadWrite, Eeprom Voltage(0.1) (50 ~ 160) = 60,
+    MaxVoltageLimit: 13, 1, U8, ReadWrite, Eeprom Voltage(0.1) (50 ~ 160) = 160,
+    MaxTorque: 14, 2, U16, ReadWrite, Eeprom (0 ~ 1023) = 1023,
+    StatusReturnLevel: 16, 1, U8, ReadWrite, Eeprom (0 ~ 2) = 2,
+    AlarmLed: 17, 1, U8, ReadWrite, Eeprom = 36,
+    Shutdown: 18, 1, U8, ReadWrite, Eeprom = 36,
+    MultiTurnOffset: 20, 2, I16, ReadWrite, Eeprom (-24576 ~ 24576) = 0,
+    ResolutionDivider: 22, 1, U8, ReadWrite, Eeprom (1 ~ 4) = 1,
+    TorqueEnable: 24, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    Led: 25, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    DGain: 26, 1, U8, ReadWrite, Ram (0 ~ 254) = 0,
+    IGain: 27, 1, U8, ReadWrite, Ram (0 ~ 254) = 0,
+    PGain: 28, 1, U8, ReadWrite, Ram (0 ~ 254) [MX_12W = 8, MX_28 = 32],
+    GoalPosition: 30, 2, U16, ReadWrite, Ram Angle(0.00153589) (0 ~ 4095),
+    MovingSpeed: 32, 2, U16, ReadWrite, Ram (0 ~ 2047),
+    TorqueLimit: 34, 2, U16, ReadWrite, Ram (0 ~ 1023),
+    PresentPosition: 36, 2, U16, Read, Ram Angle(0.00153589),
+    PresentSpeed: 38, 2, U16, Read, Ram,
+    PresentLoad: 40, 2, U16, Read, Ram,
+    PresentVoltage: 42, 1, U8, Read, Ram Voltage(0.1),
+    PresentTemperature: 43, 1, U8, Read, Ram Temperature(1.0),
+    Registered: 44, 1, U8, Read, Ram (0 ~ 1) = 0,
+    Moving: 46, 1, U8, Read, Ram (0 ~ 1) = 0,
+    Lock: 47, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    Punch: 48, 2, U16, ReadWrite, Ram (0 ~ 1023) [MX_12W = 32, MX_28 = 0],
+    RealtimeTick: 50, 2, U16, Read, Ram Time(0.001) (0 ~ 32767),
+    GoalAcceleration: 73, 1, U8, ReadWrite, Ram (0 ~ 254) = 0,
+}];
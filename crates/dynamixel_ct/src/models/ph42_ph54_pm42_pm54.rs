@@ -3,63 +3,63 @@
 use crate::model;
 
 model![PH42 PH54 PM42 PM54 => {
-    ModelNumber: 0, 2,
-    ModelInformation: 2, 4,
-    FirmwareVersion: 6, 1,
-    Id: 7, 1,
-    BaudRate: 8, 1,
-    DriveMode: 10, 1,
-    OperatingMode: 11, 1,
-    HomingOffset: 20, 4,
-    MovingThreshold: 24, 4,
-    TemperatureLimit: 31, 1,
-    MaxVoltageLimit: 32, 2,
-    MinVoltageLimit: 34, 2,
-    PwmLimit: 36, 2,
-    CurrentLimit: 38, 2,
-    AccelerationLimit: 40, 4,
-    VelocityLimit: 44, 4,
-    MaxPositionLimit: 48, 4,
-    MinPositionLimit: 52, 4,
-    StartupConfiguration: 60, 1,
-    Shutdown: 63, 1,
-    TorqueEnable: 512, 1,
-    HardwareErrorStatus: 518, 1,
-    VelocityIGain: 524, 2,
-    VelocityPGain: 526, 2,
-    PositionPGain: 532, 2,
-    BusWatchdog: 546, 1,
-    GoalPwm: 548, 2,
-    GoalCurrent: 550, 2,
-    GoalVelocity: 552, 4,
-    ProfileAcceleration: 556, 4,
-    ProfileVelocity: 560, 4,
-    GoalPosition: 564, 4,
-    Moving: 570, 1,
-    MovingStatus: 571, 1,
-    PresentPwm: 572, 2,
-    PresentCurrent: 574, 2,
-    PresentVelocity: 576, 4,
-    PresentPosition: 580, 4,
-    VelocityTrajectory: 584, 4,
-    PositionTrajectory: 588, 4,
-    PresentInputVoltage: 592, 2,
-    PresentTemperature: 594, 1,
-    PositionDGain: 528, 2,
-    PositionIGain: 530, 2,
-    ProtocolType: 13, 1,
-    ExternalPortMode1: 56, 1,
-    ExternalPortMode2: 57, 1,
-    ExternalPortMode3: 58, 1,
-    ExternalPortMode4: 59, 1,
-    LedRed: 513, 1,
-    LedGreen: 514, 1,
-    LedBlue: 515, 1,
-    Feedforward2NdGain: 536, 2,
-    Feedforward1StGain: 538, 2,
-    RealtimeTick: 568, 2,
-    ExternalPortData1: 600, 2,
-    ExternalPortData2: 602, 2,
-    ExternalPortData3: 604, 2,
-    ExternalPortData4: 606, 2,
-}];
+    ModelNumber: 0, 2, U16, Read, Eeprom [PH42_020_S300_R = 2000, PH54_100_S500_R = 2010, PH54_200_S500_R = 2020, PM42_010_S260_R = 2100, PM54_040_S250_R = 2110, PM54_060_S250_R = 2120],
+    ModelInformation: 2, 4, U32, Read, Eeprom,
+    FirmwareVersion: 6, 1, U8, Read, Eeprom,
+    Id: 7, 1, U8, ReadWrite, Eeprom (0 ~ 252) = 1,
+    BaudRate: 8, 1, U8, ReadWrite, Eeprom (0 ~ 7) = 1,
+    DriveMode: 10, 1, U8, ReadWrite, Eeprom (0 ~ 13) = 0,
+    OperatingMode: 11, 1, U8, ReadWrite, Eeprom (0 ~ 16) = 3,
+    HomingOffset: 20, 4, I32, ReadWrite, Eeprom <PH42_020_S300_R: Angle(1.03427e-05), PH54_100_S500_R: Angle(6.25911e-06), PH54_200_S500_R: Angle(6.25911e-06), PM42_010_S260_R: Angle(1.19367e-05), PM54_040_S250_R: Angle(1.24955e-05), PM54_060_S250_R: Angle(1.24955e-05)> (-2147483648 ~ 2147483647) = 0,
+    MovingThreshold: 24, 4, U32, ReadWrite, Eeprom AngularVelocity(0.0010472) (0 ~ 10) = 10,
+    TemperatureLimit: 31, 1, U8, ReadWrite, Eeprom Temperature(1.0) (0 ~ 100) = 80,
+    MaxVoltageLimit: 32, 2, U16, ReadWrite, Eeprom Voltage(0.1) (150 ~ 350) = 350,
+    MinVoltageLimit: 34, 2, U16, ReadWrite, Eeprom Voltage(0.1) (150 ~ 350) = 150,
+    PwmLimit: 36, 2, U16, ReadWrite, Eeprom Ratio(0.00049776) (0 ~ 2009) = 2009,
+    CurrentLimit: 38, 2, U16, ReadWrite, Eeprom Current(0.001) {PH42_020_S300_R: (0 ~ 4500), PH54_100_S500_R: (0 ~ 15900), PH54_200_S500_R: (0 ~ 22740), PM42_010_S260_R: (0 ~ 4500), PM54_040_S250_R: (0 ~ 10300), PM54_060_S250_R: (0 ~ 15900)} [PH42_020_S300_R = 4500, PH54_100_S500_R = 15900, PH54_200_S500_R = 22740, PM42_010_S260_R = 4500, PM54_040_S250_R = 10300, PM54_060_S250_R = 15900],
+    AccelerationLimit: 40, 4, U32, ReadWrite, Eeprom AngularAcceleration(0.00174533) (0 ~ 2147483647) [PH42_020_S300_R = 10765, PH54_100_S500_R = 9982, PH54_200_S500_R = 9982, PM42_010_S260_R = 10765, PM54_040_S250_R = 9982, PM54_060_S250_R = 9982],
+    VelocityLimit: 44, 4, U32, ReadWrite, Eeprom AngularVelocity(0.0010472) {PH42_020_S300_R: (0 ~ 2900), PH54_100_S500_R: (0 ~ 2900), PH54_200_S500_R: (0 ~ 2900), PM42_010_S260_R: (0 ~ 2970), PM54_040_S250_R: (0 ~ 2920), PM54_060_S250_R: (0 ~ 2920)} [PH42_020_S300_R = 2900, PH54_100_S500_R = 2900, PH54_200_S500_R = 2900, PM42_010_S260_R = 2970, PM54_040_S250_R = 2920, PM54_060_S250_R = 2920],
+    MaxPositionLimit: 48, 4, I32, ReadWrite, Eeprom <PH42_020_S300_R: Angle(1.03427e-05), PH54_100_S500_R: Angle(6.25911e-06), PH54_200_S500_R: Angle(6.25911e-06), PM42_010_S260_R: Angle(1.19367e-05), PM54_040_S250_R: Angle(1.24955e-05), PM54_060_S250_R: Angle(1.24955e-05)> (-2147483648 ~ 2147483647) [PH42_020_S300_R = 303454, PH54_100_S500_R = 501433, PH54_200_S500_R = 501433, PM42_010_S260_R = 263187, PM54_040_S250_R = 251173, PM54_060_S250_R = 251173],
+    MinPositionLimit: 52, 4, I32, ReadWrite, Eeprom <PH42_020_S300_R: Angle(1.03427e-05), PH54_100_S500_R: Angle(6.25911e-06), PH54_200_S500_R: Angle(6.25911e-06), PM42_010_S260_R: Angle(1.19367e-05), PM54_040_S250_R: Angle(1.24955e-05), PM54_060_S250_R: Angle(1.24955e-05)> (-2147483648 ~ 2147483647) [PH42_020_S300_R = -303454, PH54_100_S500_R = -501433, PH54_200_S500_R = -501433, PM42_010_S260_R = -263187, PM54_040_S250_R = -251173, PM54_060_S250_R = -251173],
+    StartupConfiguration: 60, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0,
+    Shutdown: 63, 1, U8, ReadWrite, Eeprom = 58,
+    TorqueEnable: 512, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    HardwareErrorStatus: 518, 1, U8, Read, Ram = 0,
+    VelocityIGain: 524, 2, U16, ReadWrite, Ram (0 ~ 32767) [PH42_020_S300_R = 14, PH54_100_S500_R = 16, PH54_200_S500_R = 16, PM42_010_S260_R = 40, PM54_040_S250_R = 16, PM54_060_S250_R = 16],
+    VelocityPGain: 526, 2, U16, ReadWrite, Ram (0 ~ 32767) [PH42_020_S300_R = 399, PH54_100_S500_R = 256, PH54_200_S500_R = 256, PM42_010_S260_R = 440, PM54_040_S250_R = 256, PM54_060_S250_R = 256],
+    PositionPGain: 532, 2, U16, ReadWrite, Ram (0 ~ 32767) = 32,
+    BusWatchdog: 546, 1, U8, ReadWrite, Ram Time(0.02) (1 ~ 127) = 0,
+    GoalPwm: 548, 2, I16, ReadWrite, Ram Ratio(0.00049776) (-PwmLimit ~ PwmLimit),
+    GoalCurrent: 550, 2, I16, ReadWrite, Ram Current(0.001) (-CurrentLimit ~ CurrentLimit),
+    GoalVelocity: 552, 4, I32, ReadWrite, Ram AngularVelocity(0.0010472) (-VelocityLimit ~ VelocityLimit),
+    ProfileAcceleration: 556, 4, U32, ReadWrite, Ram AngularAcceleration(0.00174533) (0 ~ AccelerationLimit) = 0,
+    ProfileVelocity: 560, 4, U32, ReadWrite, Ram AngularVelocity(0.0010472) (0 ~ VelocityLimit) = 0,
+    GoalPosition: 564, 4, I32, ReadWrite, Ram <PH42_020_S300_R: Angle(1.03427e-05), PH54_100_S500_R: Angle(6.25911e-06), PH54_200_S500_R: Angle(6.25911e-06), PM42_010_S260_R: Angle(1.19367e-05), PM54_040_S250_R: Angle(1.24955e-05), PM54_060_S250_R: Angle(1.24955e-05)> (MinPositionLimit ~ MaxPositionLimit),
+    Moving: 570, 1, U8, Read, Ram (0 ~ 1) = 0,
+    MovingStatus: 571, 1, U8, Read, Ram = 0,
+    PresentPwm: 572, 2, I16, Read, Ram Ratio(0.00049776),
+    PresentCurrent: 574, 2, I16, Read, Ram Current(0.001),
+    PresentVelocity: 576, 4, I32, Read, Ram AngularVelocity(0.0010472),
+    PresentPosition: 580, 4, I32, Read, Ram <PH42_020_S300_R: Angle(1.03427e-05), PH54_100_S500_R: Angle(6.25911e-06), PH54_200_S500_R: Angle(6.25911e-06), PM42_010_S260_R: Angle(1.19367e-05), PM54_040_S250_R: Angle(1.24955e-05), PM54_060_S250_R: Angle(1.24955e-05)>,
+    VelocityTrajectory: 584, 4, I32, Read, Ram AngularVelocity(0.0010472),
+    PositionTrajectory: 588, 4, I32, Read, Ram <PH42_020_S300_R: Angle(1.03427e-05), PH54_100_S500_R: Angle(6.25911e-06), PH54_200_S500_R: Angle(6.25911e-06), PM42_010_S260_R: Angle(1.19367e-05), PM54_040_S250_R: Angle(1.24955e-05), PM54_060_S250_R: Angle(1.24955e-05)>,
+    PresentInputVoltage: 592, 2, U16, Read, Ram Voltage(0.1),
+    PresentTemperature: 594, 1, U8, Read, Ram Temperature(1.0),
+    PositionDGain: 528, 2, U16, ReadWrite, Ram (0 ~ 32767) = 0,
+    PositionIGain: 530, 2, U16, ReadWrite, Ram (0 ~ 32767) = 0,
+    ProtocolType: 13, 1, U8, ReadWrite, Eeprom (2 ~ 2) = 2,
+    ExternalPortMode1: 56, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0,
+    ExternalPortMode2: 57, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0,
+    ExternalPortMode3: 58, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0,
+    ExternalPortMode4: 59, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0,
+    LedRed: 513, 1, U8, ReadWrite, Ram (0 ~ 255) = 0,
+    LedGreen: 514, 1, U8, ReadWrite, Ram (0 ~ 255) = 0,
+    LedBlue: 515, 1, U8, ReadWrite, Ram (0 ~ 255) = 0,
+    Feedforward2NdGain: 536, 2, U16, ReadWrite, Ram (0 ~ 32767) = 0,
+    Feedforward1StGain: 538, 2, U16, ReadWrite, Ram (0 ~ 32767) = 0,
+    RealtimeTick: 568, 2, U16, Read, Ram Time(0.001) (0 ~ 32767),
+    ExternalPortData1: 600, 2, U16, ReadWrite, Ram (0 ~ 4095) = 0,
+    ExternalPortData2: 602, 2, U16, ReadWrite, Ram (0 ~ 4095) = 0,
+    ExternalPortData3: 604, 2, U16, ReadWrite, Ram (0 ~ 4095) = 0,
+    ExternalPortData4: 606, 2, U16, ReadWrite, Ram (0 ~ 4095) = 0,
+} indirect [(168, 634, 128)]];
@@ -0,0 +1,52 @@
+//! Dynamixel RHP12RN model definitions.
+
+use crate::model;
+
+model![RHP12RN => {
+    ModelNumber: 0, 2, U16, Read, Eeprom = 35073,
+    ModelInformation: 2, 4, U32, Read, Eeprom,
+    FirmwareVersion: 6, 1, U8, Read, Eeprom,
+    Id: 7, 1, U8, ReadWrite, Eeprom (0 ~ 252) = 1,
+    BaudRate: 8, 1, U8, ReadWrite, Eeprom (0 ~ 8) = 1,
+    ReturnDelayTime: 9, 1, U8, ReadWrite, Eeprom Time(2e-06) (0 ~ 254) = 250,
+    OperatingMode: 11, 1, U8, ReadWrite, Eeprom (0 ~ 5) = 5,
+    HomingOffset: 13, 4, I32, ReadWrite, Eeprom (-1150 ~ 1150) = 0,
+    MovingThreshold: 17, 4, U32, ReadWrite, Eeprom (0 ~ 2047) = 10,
+    TemperatureLimit: 21, 1, U8, ReadWrite, Eeprom Temperature(1.0) (0 ~ 100) = 80,
+    MaxVoltageLimit: 22, 2, U16, ReadWrite, Eeprom Voltage(0.1) (150 ~ 300) = 300,
+    MinVoltageLimit: 24, 2, U16, ReadWrite, Eeprom Voltage(0.1) (150 ~ 300) = 150,
+    AccelerationLimit: 26, 4, U32, ReadWrite, Eeprom (0 ~ 2147483647) = 255,
+    CurrentLimit: 30, 2, U16, ReadWrite, Eeprom (0 ~ 820) = 820,
+    VelocityLimit: 32, 4, U32, ReadWrite, Eeprom (0 ~ 2147483647) = 1700,
+    MaxPositionLimit: 36, 4, U32, ReadWrite, Eeprom (0 ~ 1150) = 1150,
+    MinPositionLimit: 40, 4, U32, ReadWrite, Eeprom (0 ~ 1150) = 0,
+    Shutdown: 48, 1, U8, ReadWrite, Eeprom = 58,
+    TorqueEnable: 562, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    StatusReturnLevel: 891, 1, U8, ReadWrite, Ram (0 ~ 2) = 2,
+    RegisteredInstruction: 890, 1, U8, Read, Ram (0 ~ 1) = 0,
+    HardwareErrorStatus: 892, 1, U8, Read, Ram = 0,
+    VelocityIGain: 586, 2, U16, ReadWrite, Ram (0 ~ 32767) = 40,
+    VelocityPGain: 588, 2, U16, ReadWrite, Ram (0 ~ 32767) = 440,
+    PositionPGain: 594, 2, U16, ReadWrite, Ram (0 ~ 32767) = 32,
+    GoalCurrent: 604, 2, I16, ReadWrite, Ram (-CurrentLimit ~ CurrentLimit) = 0,
+    GoalVelocity: 600, 4, I32, ReadWrite, Ram (-VelocityLimit ~ VelocityLimit) = 0,
+    GoalPosition: 596, 4, I32, ReadWrite, Ram (MinPositionLimit ~ MaxPositionLimit),
+    Moving: 610, 1, U8, Read, Ram (0 ~ 1) = 0,
+    PresentCurrent: 621, 2, I16, Read, Ram,
+    PresentVelocity: 615, 4, I32, Read, Ram,
+    PresentPosition: 611, 4, I32, Read, Ram,
+    PresentInputVoltage: 623, 2, U16, Read, Ram Voltage(0.1),
+    PresentTemperature: 625, 1, U8, Read, Ram Temperature(1.0),
+    ExternalPortMode1: 44, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0,
+    ExternalPortMode2: 45, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0,
+    ExternalPortMode3: 46, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0,
+    ExternalPortMode4: 47, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0,
+    LedRed: 563, 1, U8, ReadWrite, Ram (0 ~ 255) = 0,
+    LedGreen: 564, 1, U8, ReadWrite, Ram (0 ~ 255) = 0,
+    LedBlue: 565, 1, U8, ReadWrite, Ram (0 ~ 255) = 0,
+    ExternalPortData1: 626, 2, U16, ReadWrite, Ram (0 ~ 4095) = 0,
+    ExternalPortData2: 628, 2, U16, ReadWrite, Ram (0 ~ 4095) = 0,
+    ExternalPortData3: 630, 2, U16, ReadWrite, Ram (0 ~ 4095) = 0,
+    ExternalPortData4: 632, 2, U16, ReadWrite, Ram (0 ~ 4095) = 0,
+    GoalAcceleration: 606, 4, U32, ReadWrite, Ram (0 ~ AccelerationLimit) = 0,
+} indirect [(49, 634, 256)]];
@@ -0,0 +1,67 @@
+//! Dynamixel RHP12RNA model definitions.
+
+use crate::model;
+
+model![RHP12RNA => {
+    ModelNumber: 0, 2, U16, Read, Eeprom = 35074,
+    ModelInformation: 2, 4, U32, Read, Eeprom,
+    FirmwareVersion: 6, 1, U8, Read, Eeprom,
+    Id: 7, 1, U8, ReadWrite, Eeprom (0 ~ 252) = 1,
+    BaudRate: 8, 1, U8, ReadWrite, Eeprom (0 ~ 6) = 1,
+    ReturnDelayTime: 9, 1, U8, ReadWrite, Eeprom Time(2e-06) (0 ~ 254) = 250,
+    OperatingMode: 11, 1, U8, ReadWrite, Eeprom (0 ~ 5) = 5,
+    SecondaryId: 12, 1, U8, ReadWrite, Eeprom (0 ~ 255) = 255,
+    HomingOffset: 20, 4, I32, ReadWrite, Eeprom (-1150 ~ 1150) = 0,
+    MovingThreshold: 24, 4, U32, ReadWrite, Eeprom AngularVelocity(0.0010472) (0 ~ 10) = 10,
+    TemperatureLimit: 31, 1, U8, ReadWrite, Eeprom Temperature(1.0) (0 ~ 100) = 80,
+    MaxVoltageLimit: 32, 2, U16, ReadWrite, Eeprom Voltage(0.1) (150 ~ 300) = 300,
+    MinVoltageLimit: 34, 2, U16, ReadWrite, Eeprom Voltage(0.1) (150 ~ 300) = 150,
+    PwmLimit: 36, 2, U16, ReadWrite, Eeprom Ratio(0.00049776) (0 ~ 2009) = 2009,
+    CurrentLimit: 38, 2, U16, ReadWrite, Eeprom (0 ~ 1984) = 1984,
+    AccelerationLimit: 40, 4, U32, ReadWrite, Eeprom (0 ~ 2147483647) = 3447,
+    VelocityLimit: 44, 4, U32, ReadWrite, Eeprom AngularVelocity(0.0010472) (0 ~ 2970) = 2970,
+    MaxPositionLimit: 48, 4, U32, ReadWrite, Eeprom (0 ~ 1150) = 1150,
+    MinPositionLimit: 52, 4, U32, ReadWrite, Eeprom (0 ~ 1150) = 0,
+    Shutdown: 63, 1, U8, ReadWrite, Eeprom = 58,
+    TorqueEnable: 512, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    StatusReturnLevel: 516, 1, U8, ReadWrite, Ram (0 ~ 2) = 2,
+    RegisteredInstruction: 517, 1, U8, Read, Ram (0 ~ 1) = 0,
+    HardwareErrorStatus: 518, 1, U8, Read, Ram = 0,
+    VelocityIGain: 524, 2, U16, ReadWrite, Ram (0 ~ 32767) = 40,
+    VelocityPGain: 526, 2, U16, ReadWrite, Ram (0 ~ 32767) = 440,
+    PositionPGain: 532, 2, U16, ReadWrite, Ram (0 ~ 32767) = 32,
+    BusWatchdog: 546, 1, U8, ReadWrite, Ram Time(0.02) (1 ~ 127) = 0,
+    GoalPwm: 548, 2, I16, ReadWrite, Ram Ratio(0.00049776) (-PwmLimit ~ PwmLimit),
+    GoalCurrent: 550, 2, I16, ReadWrite, Ram (-CurrentLimit ~ CurrentLimit),
+    GoalVelocity: 552, 4, I32, ReadWrite, Ram AngularVelocity(0.0010472) (-VelocityLimit ~ VelocityLimit),
+    ProfileAcceleration: 556, 4, U32, ReadWrite, Ram (0 ~ AccelerationLimit) = 0,
+    ProfileVelocity: 560, 4, U32, ReadWrite, Ram AngularVelocity(0.0010472) (0 ~ VelocityLimit) = 0,
+    GoalPosition: 564, 4, I32, ReadWrite, Ram (MinPositionLimit ~ MaxPositionLimit),
+    Moving: 570, 1, U8, Read, Ram (0 ~ 1) = 0,
+    MovingStatus: 571, 1, U8, Read, Ram = 0,
+    PresentPwm: 572, 2, I16, Read, Ram Ratio(0.00049776),
+    PresentCurrent: 574, 2, I16, Read, Ram,
+    PresentVelocity: 576, 4, I32, Read, Ram AngularVelocity(0.0010472),
+    PresentPosition: 580, 4, I32, Read, Ram,
+    VelocityTrajectory: 584, 4, I32, Read, Ram AngularVelocity(0.0010472),
+    PositionTrajectory: 588, 4, I32, Read, Ram,
+    PresentInputVoltage: 592, 2, U16, Read, Ram Voltage(0.1),
+    PresentTemperature: 594, 1, U8, Read, Ram Temperature(1.0),
+    PositionDGain: 528, 2, U16, ReadWrite, Ram (0 ~ 32767) = 0,
+    PositionIGain: 530, 2, U16, ReadWrite, Ram (0 ~ 32767) = 0,
+    ProtocolType: 13, 1, U8, ReadWrite, Eeprom (2 ~ 2) = 2,
+    ExternalPortMode1: 56, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0,
+    ExternalPortMode2: 57, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0,
+    ExternalPortMode3: 58, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0,
+    ExternalPortMode4: 59, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0,
+    LedRed: 513, 1, U8, ReadWrite, Ram (0 ~ 255) = 0,
+    LedGreen: 514, 1, U8, ReadWrite, Ram (0 ~ 255) = 0,
+    LedBlue: 515, 1, U8, ReadWrite, Ram (0 ~ 255) = 0,
+    Feedforward2NdGain: 536, 2, U16, ReadWrite, Ram (0 ~ 32767) = 0,
+    Feedforward1StGain: 538, 2, U16, ReadWrite, Ram (0 ~ 32767) = 0,
+    RealtimeTick: 568, 2, U16, Read, Ram Time(0.001) (0 ~ 32767),
+    ExternalPortData1: 600, 2, U16, ReadWrite, Ram (0 ~ 4095) = 0,
+    ExternalPortData2: 602, 2, U16, ReadWrite, Ram (0 ~ 4095) = 0,
+    ExternalPortData3: 604, 2, U16, ReadWrite, Ram (0 ~ 4095) = 0,
+    ExternalPortData4: 606, 2, U16, ReadWrite, Ram (0 ~ 4095) = 0,
+} indirect [(168, 634, 128)]];
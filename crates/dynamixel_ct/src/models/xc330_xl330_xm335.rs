@@ -3,58 +3,58 @@
 use crate::model;
 
 model![XC330 XL330 XM335 => {
-    ModelNumber: 0, 2,
-    ModelInformation: 2, 4,
-    FirmwareVersion: 6, 1,
-    Id: 7, 1,
-    BaudRate: 8, 1,
-    ReturnDelayTime: 9, 1,
-    DriveMode: 10, 1,
-    OperatingMode: 11, 1,
-    SecondaryId: 12, 1,
-    HomingOffset: 20, 4,
-    MovingThreshold: 24, 4,
-    TemperatureLimit: 31, 1,
-    MaxVoltageLimit: 32, 2,
-    MinVoltageLimit: 34, 2,
-    PwmLimit: 36, 2,
-    CurrentLimit: 38, 2,
-    VelocityLimit: 44, 4,
-    MaxPositionLimit: 48, 4,
-    MinPositionLimit: 52, 4,
-    StartupConfiguration: 60, 1,
-    Shutdown: 63, 1,
-    TorqueEnable: 64, 1,
-    Led: 65, 1,
-    StatusReturnLevel: 68, 1,
-    RegisteredInstruction: 69, 1,
-    HardwareErrorStatus: 70, 1,
-    VelocityIGain: 76, 2,
-    VelocityPGain: 78, 2,
-    PositionPGain: 84, 2,
-    BusWatchdog: 98, 1,
-    GoalPwm: 100, 2,
-    GoalCurrent: 102, 2,
-    GoalVelocity: 104, 4,
-    ProfileAcceleration: 108, 4,
-    ProfileVelocity: 112, 4,
-    GoalPosition: 116, 4,
-    Moving: 122, 1,
-    MovingStatus: 123, 1,
-    PresentPwm: 124, 2,
-    PresentCurrent: 126, 2,
-    PresentVelocity: 128, 4,
-    PresentPosition: 132, 4,
-    VelocityTrajectory: 136, 4,
-    PositionTrajectory: 140, 4,
-    PresentInputVoltage: 144, 2,
-    PresentTemperature: 146, 1,
-    BackupReady: 147, 1,
-    PositionDGain: 80, 2,
-    PositionIGain: 82, 2,
-    ProtocolType: 13, 1,
-    Feedforward2NdGain: 88, 2,
-    Feedforward1StGain: 90, 2,
-    RealtimeTick: 120, 2,
-    PwmSlope: 62, 1,
-}];
+    ModelNumber: 0, 2, U16, Read, Eeprom [XC330_M181 = 1230, XC330_M288 = 1240, XC330_T181 = 1210, XC330_T288 = 1220, XL330_M077 = 1190, XL330_M288 = 1200, XM335_T323 = 1701],
+    ModelInformation: 2, 4, U32, Read, Eeprom,
+    FirmwareVersion: 6, 1, U8, Read, Eeprom,
+    Id: 7, 1, U8, ReadWrite, Eeprom (0 ~ 252) = 1,
+    BaudRate: 8, 1, U8, ReadWrite, Eeprom (0 ~ 7) = 1,
+    ReturnDelayTime: 9, 1, U8, ReadWrite, Eeprom Time(2e-06) (0 ~ 254) = 250,
+    DriveMode: 10, 1, U8, ReadWrite, Eeprom (0 ~ 13) = 0,
+    OperatingMode: 11, 1, U8, ReadWrite, Eeprom (0 ~ 16) = 3,
+    SecondaryId: 12, 1, U8, ReadWrite, Eeprom (0 ~ 252) = 255,
+    HomingOffset: 20, 4, I32, ReadWrite, Eeprom Angle(0.00153398) (-1044479 ~ 1044479) = 0,
+    MovingThreshold: 24, 4, U32, ReadWrite, Eeprom AngularVelocity(0.0239808) (0 ~ 1023) = 10,
+    TemperatureLimit: 31, 1, U8, ReadWrite, Eeprom Temperature(1.0) (0 ~ 100) = 70,
+    MaxVoltageLimit: 32, 2, U16, ReadWrite, Eeprom Voltage(0.1) {XC330_M181: (31 ~ 70), XC330_M288: (31 ~ 70), XC330_T181: (55 ~ 140), XC330_T288: (55 ~ 140), XL330_M077: (31 ~ 70), XL330_M288: (31 ~ 70), XM335_T323: (55 ~ 140)} [XC330_M181 = 70, XC330_M288 = 70, XC330_T181 = 140, XC330_T288 = 140, XL330_M077 = 70, XL330_M288 = 70, XM335_T323 = 140],
+    MinVoltageLimit: 34, 2, U16, ReadWrite, Eeprom Voltage(0.1) {XC330_M181: (31 ~ 70), XC330_M288: (31 ~ 70), XC330_T181: (55 ~ 140), XC330_T288: (55 ~ 140), XL330_M077: (31 ~ 70), XL330_M288: (31 ~ 70), XM335_T323: (55 ~ 140)} [XC330_M181 = 35, XC330_M288 = 35, XC330_T181 = 55, XC330_T288 = 55, XL330_M077 = 35, XL330_M288 = 35, XM335_T323 = 55],
+    PwmLimit: 36, 2, U16, ReadWrite, Eeprom Ratio(0.00113) (0 ~ 885) = 885,
+    CurrentLimit: 38, 2, U16, ReadWrite, Eeprom Current(0.001) {XC330_M181: (0 ~ 1750), XC330_M288: (0 ~ 1750), XC330_T181: (0 ~ 910), XC330_T288: (0 ~ 910), XL330_M077: (0 ~ 1750), XL330_M288: (0 ~ 1750), XM335_T323: (0 ~ 1750)} [XC330_M181 = 1750, XC330_M288 = 1750, XC330_T181 = 910, XC330_T288 = 910, XL330_M077 = 1750, XL330_M288 = 1750, XM335_T323 = 1750],
+    VelocityLimit: 44, 4, U32, ReadWrite, Eeprom AngularVelocity(0.0239808) (0 ~ 2047) [XC330_M181 = 1023, XC330_M288 = 445, XC330_T181 = 1023, XC330_T288 = 445, XL330_M077 = 2047, XL330_M288 = 445, XM335_T323 = 300],
+    MaxPositionLimit: 48, 4, U32, ReadWrite, Eeprom Angle(0.00153398) (0 ~ 4095) = 4095,
+    MinPositionLimit: 52, 4, U32, ReadWrite, Eeprom Angle(0.00153398) (0 ~ 4095) = 0,
+    StartupConfiguration: 60, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0,
+    Shutdown: 63, 1, U8, ReadWrite, Eeprom = 53,
+    TorqueEnable: 64, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    Led: 65, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    StatusReturnLevel: 68, 1, U8, ReadWrite, Ram (0 ~ 2) = 2,
+    RegisteredInstruction: 69, 1, U8, Read, Ram (0 ~ 1) = 0,
+    HardwareErrorStatus: 70, 1, U8, Read, Ram = 0,
+    VelocityIGain: 76, 2, U16, ReadWrite, Ram (0 ~ 16383) = 1600,
+    VelocityPGain: 78, 2, U16, ReadWrite, Ram (0 ~ 16383) = 180,
+    PositionPGain: 84, 2, U16, ReadWrite, Ram (0 ~ 16383) = 400,
+    BusWatchdog: 98, 1, U8, ReadWrite, Ram Time(0.02) (1 ~ 127) = 0,
+    GoalPwm: 100, 2, I16, ReadWrite, Ram Ratio(0.00113) (-PwmLimit ~ PwmLimit),
+    GoalCurrent: 102, 2, I16, ReadWrite, Ram Current(0.001) (-CurrentLimit ~ CurrentLimit),
+    GoalVelocity: 104, 4, I32, ReadWrite, Ram AngularVelocity(0.0239808) (-VelocityLimit ~ VelocityLimit),
+    ProfileAcceleration: 108, 4, U32, ReadWrite, Ram AngularAcceleration(0.374508) (0 ~ 32767) = 0,
+    ProfileVelocity: 112, 4, U32, ReadWrite, Ram AngularVelocity(0.0239808) (0 ~ 32767) = 0,
+    GoalPosition: 116, 4, I32, ReadWrite, Ram Angle(0.00153398) (MinPositionLimit ~ MaxPositionLimit),
+    Moving: 122, 1, U8, Read, Ram (0 ~ 1) = 0,
+    MovingStatus: 123, 1, U8, Read, Ram = 0,
+    PresentPwm: 124, 2, I16, Read, Ram Ratio(0.00113),
+    PresentCurrent: 126, 2, I16, Read, Ram Current(0.001),
+    PresentVelocity: 128, 4, I32, Read, Ram AngularVelocity(0.0239808),
+    PresentPosition: 132, 4, I32, Read, Ram Angle(0.00153398),
+    VelocityTrajectory: 136, 4, I32, Read, Ram AngularVelocity(0.0239808),
+    PositionTrajectory: 140, 4, I32, Read, Ram Angle(0.00153398),
+    PresentInputVoltage: 144, 2, U16, Read, Ram Voltage(0.1),
+    PresentTemperature: 146, 1, U8, Read, Ram Temperature(1.0),
+    BackupReady: 147, 1, U8, Read, Ram (0 ~ 1) = 0,
+    PositionDGain: 80, 2, U16, ReadWrite, Ram (0 ~ 16383) = 0,
+    PositionIGain: 82, 2, U16, ReadWrite, Ram (0 ~ 16383) = 0,
+    ProtocolType: 13, 1, U8, ReadWrite, Eeprom (1 ~ 2) = 2,
+    Feedforward2NdGain: 88, 2, U16, ReadWrite, Ram (0 ~ 16383) = 0,
+    Feedforward1StGain: 90, 2, U16, ReadWrite, Ram (0 ~ 16383) = 0,
+    RealtimeTick: 120, 2, U16, Read, Ram Time(0.001) (0 ~ 32767),
+    PwmSlope: 62, 1, U8, ReadWrite, Eeprom (1 ~ 255) = 140,
+} indirect [(168, 224, 20), (578, 634, 8)]];
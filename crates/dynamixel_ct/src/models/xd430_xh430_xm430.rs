@@ -3,57 +3,57 @@
 use crate::model;
 
 model![XD430 XH430 XM430 => {
-    ModelNumber: 0, 2,
-    ModelInformation: 2, 4,
-    FirmwareVersion: 6, 1,
-    Id: 7, 1,
-    BaudRate: 8, 1,
-    ReturnDelayTime: 9, 1,
-    DriveMode: 10, 1,
-    OperatingMode: 11, 1,
-    SecondaryId: 12, 1,
-    HomingOffset: 20, 4,
-    MovingThreshold: 24, 4,
-    TemperatureLimit: 31, 1,
-    MaxVoltageLimit: 32, 2,
-    MinVoltageLimit: 34, 2,
-    PwmLimit: 36, 2,
-    CurrentLimit: 38, 2,
-    VelocityLimit: 44, 4,
-    MaxPositionLimit: 48, 4,
-    MinPositionLimit: 52, 4,
-    StartupConfiguration: 60, 1,
-    Shutdown: 63, 1,
-    TorqueEnable: 64, 1,
-    Led: 65, 1,
-    StatusReturnLevel: 68, 1,
-    RegisteredInstruction: 69, 1,
-    HardwareErrorStatus: 70, 1,
-    VelocityIGain: 76, 2,
-    VelocityPGain: 78, 2,
-    PositionPGain: 84, 2,
-    BusWatchdog: 98, 1,
-    GoalPwm: 100, 2,
-    GoalCurrent: 102, 2,
-    GoalVelocity: 104, 4,
-    ProfileAcceleration: 108, 4,
-    ProfileVelocity: 112, 4,
-    GoalPosition: 116, 4,
-    Moving: 122, 1,
-    MovingStatus: 123, 1,
-    PresentPwm: 124, 2,
-    PresentCurrent: 126, 2,
-    PresentVelocity: 128, 4,
-    PresentPosition: 132, 4,
-    VelocityTrajectory: 136, 4,
-    PositionTrajectory: 140, 4,
-    PresentInputVoltage: 144, 2,
-    PresentTemperature: 146, 1,
-    BackupReady: 147, 1,
-    PositionDGain: 80, 2,
-    PositionIGain: 82, 2,
-    ProtocolType: 13, 1,
-    Feedforward2NdGain: 88, 2,
-    Feedforward1StGain: 90, 2,
-    RealtimeTick: 120, 2,
-}];
+    ModelNumber: 0, 2, U16, Read, Eeprom [XD430_T210 = 1011, XD430_T350 = 1001, XH430_V210 = 1050, XH430_V350 = 1040, XH430_W210 = 1010, XH430_W350 = 1000, XM430_W210 = 1030, XM430_W350 = 1020],
+    ModelInformation: 2, 4, U32, Read, Eeprom,
+    FirmwareVersion: 6, 1, U8, Read, Eeprom,
+    Id: 7, 1, U8, ReadWrite, Eeprom (0 ~ 252) = 1,
+    BaudRate: 8, 1, U8, ReadWrite, Eeprom (0 ~ 7) = 1,
+    ReturnDelayTime: 9, 1, U8, ReadWrite, Eeprom Time(2e-06) (0 ~ 254) = 250,
+    DriveMode: 10, 1, U8, ReadWrite, Eeprom (0 ~ 13) = 0,
+    OperatingMode: 11, 1, U8, ReadWrite, Eeprom (0 ~ 16) = 3,
+    SecondaryId: 12, 1, U8, ReadWrite, Eeprom (0 ~ 252) = 255,
+    HomingOffset: 20, 4, I32, ReadWrite, Eeprom Angle(0.00153398) (-1044479 ~ 1044479) = 0,
+    MovingThreshold: 24, 4, U32, ReadWrite, Eeprom AngularVelocity(0.0239808) (0 ~ 1023) = 10,
+    TemperatureLimit: 31, 1, U8, ReadWrite, Eeprom Temperature(1.0) (0 ~ 100) = 80,
+    MaxVoltageLimit: 32, 2, U16, ReadWrite, Eeprom Voltage(0.1) {XD430_T210: (95 ~ 160), XD430_T350: (95 ~ 160), XH430_V210: (100 ~ 300), XH430_V350: (100 ~ 300), XH430_W210: (95 ~ 160), XH430_W350: (95 ~ 160), XM430_W210: (95 ~ 160), XM430_W350: (95 ~ 160)} [XD430_T210 = 160, XD430_T350 = 160, XH430_V210 = 300, XH430_V350 = 300, XH430_W210 = 160, XH430_W350 = 160, XM430_W210 = 160, XM430_W350 = 160],
+    MinVoltageLimit: 34, 2, U16, ReadWrite, Eeprom Voltage(0.1) {XD430_T210: (95 ~ 160), XD430_T350: (95 ~ 160), XH430_V210: (100 ~ 300), XH430_V350: (100 ~ 300), XH430_W210: (95 ~ 160), XH430_W350: (95 ~ 160), XM430_W210: (95 ~ 160), XM430_W350: (95 ~ 160)} = 95,
+    PwmLimit: 36, 2, U16, ReadWrite, Eeprom Ratio(0.00113) (0 ~ 885) = 885,
+    CurrentLimit: 38, 2, U16, ReadWrite, Eeprom <XD430_T210: Current(0.00269), XD430_T350: Current(0.00269), XH430_V210: Current(0.00134), XH430_V350: Current(0.00134), XH430_W210: Current(0.00269), XH430_W350: Current(0.00269), XM430_W210: Current(0.00269), XM430_W350: Current(0.00269)> {XD430_T210: (0 ~ 1193), XD430_T350: (0 ~ 1193), XH430_V210: (0 ~ 689), XH430_V350: (0 ~ 689), XH430_W210: (0 ~ 648), XH430_W350: (0 ~ 648), XM430_W210: (0 ~ 1193), XM430_W350: (0 ~ 1193)} [XD430_T210 = 1193, XD430_T350 = 1193, XH430_V210 = 689, XH430_V350 = 689, XH430_W210 = 648, XH430_W350 = 648, XM430_W210 = 1193, XM430_W350 = 1193],
+    VelocityLimit: 44, 4, U32, ReadWrite, Eeprom AngularVelocity(0.0239808) (0 ~ 1023) [XD430_T210 = 210, XD430_T350 = 128, XH430_V210 = 210, XH430_V350 = 128, XH430_W210 = 210, XH430_W350 = 128, XM430_W210 = 330, XM430_W350 = 200],
+    MaxPositionLimit: 48, 4, U32, ReadWrite, Eeprom Angle(0.00153398) (0 ~ 4095) = 4095,
+    MinPositionLimit: 52, 4, U32, ReadWrite, Eeprom Angle(0.00153398) (0 ~ 4095) = 0,
+    StartupConfiguration: 60, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0 @ 45,
+    Shutdown: 63, 1, U8, ReadWrite, Eeprom = 52,
+    TorqueEnable: 64, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    Led: 65, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    StatusReturnLevel: 68, 1, U8, ReadWrite, Ram (0 ~ 2) = 2,
+    RegisteredInstruction: 69, 1, U8, Read, Ram (0 ~ 1) = 0,
+    HardwareErrorStatus: 70, 1, U8, Read, Ram = 0,
+    VelocityIGain: 76, 2, U16, ReadWrite, Ram (0 ~ 16383) = 1920,
+    VelocityPGain: 78, 2, U16, ReadWrite, Ram (0 ~ 16383) = 100,
+    PositionPGain: 84, 2, U16, ReadWrite, Ram (0 ~ 16383) = 800,
+    BusWatchdog: 98, 1, U8, ReadWrite, Ram Time(0.02) (1 ~ 127) = 0 @ 38,
+    GoalPwm: 100, 2, I16, ReadWrite, Ram Ratio(0.00113) (-PwmLimit ~ PwmLimit),
+    GoalCurrent: 102, 2, I16, ReadWrite, Ram <XD430_T210: Current(0.00269), XD430_T350: Current(0.00269), XH430_V210: Current(0.00134), XH430_V350: Current(0.00134), XH430_W210: Current(0.00269), XH430_W350: Current(0.00269), XM430_W210: Current(0.00269), XM430_W350: Current(0.00269)> (-CurrentLimit ~ CurrentLimit),
+    GoalVelocity: 104, 4, I32, ReadWrite, Ram AngularVelocity(0.0239808) (-VelocityLimit ~ VelocityLimit),
+    ProfileAcceleration: 108, 4, U32, ReadWrite, Ram AngularAcceleration(0.374508) (0 ~ 32767) = 0,
+    ProfileVelocity: 112, 4, U32, ReadWrite, Ram AngularVelocity(0.0239808) (0 ~ 32767) = 0,
+    GoalPosition: 116, 4, I32, ReadWrite, Ram Angle(0.00153398) (MinPositionLimit ~ MaxPositionLimit),
+    Moving: 122, 1, U8, Read, Ram (0 ~ 1) = 0,
+    MovingStatus: 123, 1, U8, Read, Ram = 0,
+    PresentPwm: 124, 2, I16, Read, Ram Ratio(0.00113),
+    PresentCurrent: 126, 2, I16, Read, Ram <XD430_T210: Current(0.00269), XD430_T350: Current(0.00269), XH430_V210: Current(0.00134), XH430_V350: Current(0.00134), XH430_W210: Current(0.00269), XH430_W350: Current(0.00269), XM430_W210: Current(0.00269), XM430_W350: Current(0.00269)>,
+    PresentVelocity: 128, 4, I32, Read, Ram AngularVelocity(0.0239808),
+    PresentPosition: 132, 4, I32, Read, Ram Angle(0.00153398),
+    VelocityTrajectory: 136, 4, I32, Read, Ram AngularVelocity(0.0239808),
+    PositionTrajectory: 140, 4, I32, Read, Ram Angle(0.00153398),
+    PresentInputVoltage: 144, 2, U16, Read, Ram Voltage(0.1),
+    PresentTemperature: 146, 1, U8, Read, Ram Temperature(1.0),
+    BackupReady: 147, 1, U8, Read, Ram (0 ~ 1) = 0 @ 45,
+    PositionDGain: 80, 2, U16, ReadWrite, Ram (0 ~ 16383) = 0,
+    PositionIGain: 82, 2, U16, ReadWrite, Ram (0 ~ 16383) = 0,
+    ProtocolType: 13, 1, U8, ReadWrite, Eeprom (1 ~ 2) = 2,
+    Feedforward2NdGain: 88, 2, U16, ReadWrite, Ram (0 ~ 16383) = 0,
+    Feedforward1StGain: 90, 2, U16, ReadWrite, Ram (0 ~ 16383) = 0,
+    RealtimeTick: 120, 2, U16, Read, Ram Time(0.001) (0 ~ 32767),
+} indirect [(168, 224, 20), (578, 634, 8)]];
@@ -0,0 +1,37 @@
+//! Dynamixel XL320 model definitions.
+
+use crate::model;
+
+model![XL320 => {
+    ModelNumber: 0, 2, U16, Read, Eeprom = 350,
+    FirmwareVersion: 2, 1, U8, Read, Eeprom,
+    Id: 3, 1, U8, ReadWrite, Eeprom (0 ~ 252) = 1,
+    BaudRate: 4, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 3,
+    ReturnDelayTime: 5, 1, U8, ReadWrite, Eeprom Time(2e-06) (0 ~ 254) = 250,
+    CwAngleLimit: 6, 2, U16, ReadWrite, Eeprom Angle(0.00506145) (0 ~ 1023) = 0,
+    CcwAngleLimit: 8, 2, U16, ReadWrite, Eeprom Angle(0.00506145) (0 ~ 1023) = 1023,
+    ControlMode: 11, 1, U8, ReadWrite, Eeprom (1 ~ 2) = 2,
+    TemperatureLimit: 12, 1, U8, ReadWrite, Eeprom Temperature(1.0) (0 ~ 150) = 65,
+    MinVoltageLimit: 13, 1, U8, ReadWrite, Eeprom Voltage(0.1) (50 ~ 250) = 60,
+    MaxVoltageLimit: 14, 1, U8, ReadWrite, Eeprom Voltage(0.1) (50 ~ 250) = 90,
+    MaxTorque: 15, 2, U16, ReadWrite, Eeprom (0 ~ 1023) = 1023,
+    StatusReturnLevel: 17, 1, U8, ReadWrite, Eeprom (0 ~ 2) = 2,
+    Shutdown: 18, 1, U8, ReadWrite, Eeprom (0 ~ 7) = 3,
+    TorqueEnable: 24, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    Led: 25, 1, U8, ReadWrite, Ram (0 ~ 7) = 0,
+    DGain: 27, 1, U8, ReadWrite, Ram (0 ~ 254) = 0,
+    IGain: 28, 1, U8, ReadWrite, Ram (0 ~ 254) = 0,
+    PGain: 29, 1, U8, ReadWrite, Ram (0 ~ 254) = 32,
+    GoalPosition: 30, 2, U16, ReadWrite, Ram Angle(0.00506145) (0 ~ 1023),
+    MovingSpeed: 32, 2, U16, ReadWrite, Ram (0 ~ 2047),
+    TorqueLimit: 35, 2, U16, ReadWrite, Ram (0 ~ 1023),
+    PresentPosition: 37, 2, U16, Read, Ram Angle(0.00506145),
+    PresentSpeed: 39, 2, U16, Read, Ram,
+    PresentLoad: 41, 2, U16, Read, Ram,
+    PresentVoltage: 45, 1, U8, Read, Ram Voltage(0.1),
+    PresentTemperature: 46, 1, U8, Read, Ram Temperature(1.0),
+    RegisteredInstruction: 47, 1, U8, Read, Ram (0 ~ 1) = 0,
+    Moving: 49, 1, U8, Read, Ram (0 ~ 1) = 0,
+    HardwareErrorStatus: 50, 1, U8, Read, Ram = 0,
+    Punch: 51, 2, U16, ReadWrite, Ram (0 ~ 1023) = 32,
+}];
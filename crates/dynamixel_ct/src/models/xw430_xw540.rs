@@ -0,0 +1,58 @@
+//! Dynamixel XW430 XW540 model definitions.
+
+use crate::model;
+
+model![XW430 XW540 => {
+    ModelNumber: 0, 2, U16, Read, Eeprom [XW430_T200 = 1280, XW430_T333 = 1270, XW540_H260 = 1310, XW540_T140 = 1180, XW540_T260 = 1170],
+    ModelInformation: 2, 4, U32, Read, Eeprom,
+    FirmwareVersion: 6, 1, U8, Read, Eeprom,
+    Id: 7, 1, U8, ReadWrite, Eeprom (0 ~ 252) = 1,
+    BaudRate: 8, 1, U8, ReadWrite, Eeprom (0 ~ 7) = 1,
+    ReturnDelayTime: 9, 1, U8, ReadWrite, Eeprom Time(2e-06) (0 ~ 254) = 250,
+    DriveMode: 10, 1, U8, ReadWrite, Eeprom (0 ~ 13) = 0,
+    OperatingMode: 11, 1, U8, ReadWrite, Eeprom (0 ~ 16) = 3,
+    SecondaryId: 12, 1, U8, ReadWrite, Eeprom (0 ~ 252) = 255,
+    HomingOffset: 20, 4, I32, ReadWrite, Eeprom Angle(0.00153398) (-1044479 ~ 1044479) = 0,
+    MovingThreshold: 24, 4, U32, ReadWrite, Eeprom AngularVelocity(0.0239808) (0 ~ 1023) = 10,
+    TemperatureLimit: 31, 1, U8, ReadWrite, Eeprom Temperature(1.0) (0 ~ 100) = 80,
+    MaxVoltageLimit: 32, 2, U16, ReadWrite, Eeprom Voltage(0.1) {XW430_T200: (95 ~ 160), XW430_T333: (95 ~ 160), XW540_H260: (100 ~ 300), XW540_T140: (95 ~ 160), XW540_T260: (95 ~ 160)} [XW430_T200 = 160, XW430_T333 = 160, XW540_H260 = 300, XW540_T140 = 160, XW540_T260 = 160],
+    MinVoltageLimit: 34, 2, U16, ReadWrite, Eeprom Voltage(0.1) {XW430_T200: (95 ~ 160), XW430_T333: (95 ~ 160), XW540_H260: (100 ~ 300), XW540_T140: (95 ~ 160), XW540_T260: (95 ~ 160)} = 95,
+    PwmLimit: 36, 2, U16, ReadWrite, Eeprom Ratio(0.00113) (0 ~ 885) = 885,
+    CurrentLimit: 38, 2, U16, ReadWrite, Eeprom Current(0.00269) {XW430_T200: (0 ~ 1193), XW430_T333: (0 ~ 1193), XW540_H260: (0 ~ 2047), XW540_T140: (0 ~ 2047), XW540_T260: (0 ~ 2047)} [XW430_T200 = 1193, XW430_T333 = 1193, XW540_H260 = 2047, XW540_T140 = 2047, XW540_T260 = 2047],
+    VelocityLimit: 44, 4, U32, ReadWrite, Eeprom AngularVelocity(0.0239808) (0 ~ 1023) [XW430_T200 = 210, XW430_T333 = 128, XW540_H260 = 128, XW540_T140 = 230, XW540_T260 = 128],
+    MaxPositionLimit: 48, 4, U32, ReadWrite, Eeprom Angle(0.00153398) (0 ~ 4095) = 4095,
+    MinPositionLimit: 52, 4, U32, ReadWrite, Eeprom Angle(0.00153398) (0 ~ 4095) = 0,
+    StartupConfiguration: 60, 1, U8, ReadWrite, Eeprom (0 ~ 3) = 0,
+    Shutdown: 63, 1, U8, ReadWrite, Eeprom = 52,
+    TorqueEnable: 64, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    StatusReturnLevel: 68, 1, U8, ReadWrite, Ram (0 ~ 2) = 2,
+    RegisteredInstruction: 69, 1, U8, Read, Ram (0 ~ 1) = 0,
+    HardwareErrorStatus: 70, 1, U8, Read, Ram = 0,
+    VelocityIGain: 76, 2, U16, ReadWrite, Ram (0 ~ 16383) = 1920,
+    VelocityPGain: 78, 2, U16, ReadWrite, Ram (0 ~ 16383) = 100,
+    PositionPGain: 84, 2, U16, ReadWrite, Ram (0 ~ 16383) = 800,
+    BusWatchdog: 98, 1, U8, ReadWrite, Ram Time(0.02) (1 ~ 127) = 0,
+    GoalPwm: 100, 2, I16, ReadWrite, Ram Ratio(0.00113) (-PwmLimit ~ PwmLimit),
+    GoalCurrent: 102, 2, I16, ReadWrite, Ram Current(0.00269) (-CurrentLimit ~ CurrentLimit),
+    GoalVelocity: 104, 4, I32, ReadWrite, Ram AngularVelocity(0.0239808) (-VelocityLimit ~ VelocityLimit),
+    ProfileAcceleration: 108, 4, U32, ReadWrite, Ram AngularAcceleration(0.374508) (0 ~ 32767) = 0,
+    ProfileVelocity: 112, 4, U32, ReadWrite, Ram AngularVelocity(0.0239808) (0 ~ 32767) = 0,
+    GoalPosition: 116, 4, I32, ReadWrite, Ram Angle(0.00153398) (MinPositionLimit ~ MaxPositionLimit),
+    Moving: 122, 1, U8, Read, Ram (0 ~ 1) = 0,
+    MovingStatus: 123, 1, U8, Read, Ram = 0,
+    PresentPwm: 124, 2, I16, Read, Ram Ratio(0.00113),
+    PresentCurrent: 126, 2, I16, Read, Ram Current(0.00269),
+    PresentVelocity: 128, 4, I32, Read, Ram AngularVelocity(0.0239808),
+    PresentPosition: 132, 4, I32, Read, Ram Angle(0.00153398),
+    VelocityTrajectory: 136, 4, I32, Read, Ram AngularVelocity(0.0239808),
+    PositionTrajectory: 140, 4, I32, Read, Ram Angle(0.00153398),
+    PresentInputVoltage: 144, 2, U16, Read, Ram Voltage(0.1),
+    PresentTemperature: 146, 1, U8, Read, Ram Temperature(1.0),
+    BackupReady: 147, 1, U8, Read, Ram (0 ~ 1) = 0,
+    PositionDGain: 80, 2, U16, ReadWrite, Ram (0 ~ 16383) = 0,
+    PositionIGain: 82, 2, U16, ReadWrite, Ram (0 ~ 16383) = 0,
+    ProtocolType: 13, 1, U8, ReadWrite, Eeprom (1 ~ 2) = 2,
+    Feedforward2NdGain: 88, 2, U16, ReadWrite, Ram (0 ~ 16383) = 0,
+    Feedforward1StGain: 90, 2, U16, ReadWrite, Ram (0 ~ 16383) = 0,
+    RealtimeTick: 120, 2, U16, Read, Ram Time(0.001) (0 ~ 32767),
+} indirect [(168, 224, 20), (578, 634, 8)]];
@@ -3,79 +3,79 @@
 use crate::model;
 
 model![YM070 YM080 => {
-    ModelNumber: 0, 2,
-    ModelInformation: 2, 4,
-    FirmwareVersion: 6, 1,
-    Id: 7, 1,
-    BaudRate: 12, 1,
-    ReturnDelayTime: 13, 1,
-    DriveMode: 32, 1,
-    OperatingMode: 33, 1,
-    SecondaryId: 10, 1,
-    HomingOffset: 52, 4,
-    MovingThreshold: 48, 4,
-    MotorTemperatureLimit: 57, 1,
-    MaxVoltageLimit: 60, 2,
-    MinVoltageLimit: 62, 2,
-    PwmLimit: 64, 2,
-    CurrentLimit: 66, 2,
-    AccelerationLimit: 68, 4,
-    VelocityLimit: 72, 4,
-    MaxPositionLimit: 76, 4,
-    MinPositionLimit: 84, 4,
-    StartupConfiguration: 34, 1,
-    TorqueEnable: 512, 1,
-    Led: 513, 1,
-    StatusReturnLevel: 15, 1,
-    RegisteredInstruction: 16, 1,
-    VelocityIGain: 212, 4,
-    VelocityPGain: 216, 4,
-    PositionPGain: 232, 4,
-    BusWatchdog: 8, 2,
-    GoalPwm: 524, 2,
-    GoalCurrent: 526, 2,
-    GoalVelocity: 528, 4,
-    ProfileAcceleration: 240, 4,
-    ProfileVelocity: 244, 4,
-    GoalPosition: 532, 4,
-    MovingStatus: 541, 1,
-    PresentPwm: 544, 2,
-    PresentCurrent: 546, 2,
-    PresentVelocity: 548, 4,
-    PresentPosition: 552, 4,
-    VelocityTrajectory: 564, 4,
-    PositionTrajectory: 560, 4,
-    PresentInputVoltage: 568, 2,
-    PresentMotorTemperature: 571, 1,
-    InPositionThreshold: 40, 4,
-    FollowingErrorThreshold: 44, 4,
-    SafeStopTime: 104, 2,
-    BrakeDelay: 106, 2,
-    GoalUpdateDelay: 108, 2,
-    OverexcitationVoltage: 110, 1,
-    NormalExcitationVoltage: 111, 1,
-    OverexcitationTime: 112, 2,
-    PresentVelocityLpfFrequency: 132, 2,
-    GoalCurrentLpfFrequency: 134, 2,
-    PositionFfLpfTime: 136, 2,
-    VelocityFfLpfTime: 138, 2,
-    ErrorCode: 153, 1,
-    VelocityFfGain: 220, 4,
-    PositionDGain: 224, 4,
-    PositionIGain: 228, 4,
-    PositionFfGain: 236, 4,
-    ProfileAccelerationTime: 248, 4,
-    ProfileTime: 252, 4,
-    PwmOffset: 516, 2,
-    CurrentOffset: 518, 2,
-    VelocityOffset: 520, 4,
-    ProtocolType: 11, 1,
-    RealtimeTick: 542, 2,
-    PresentInverterTemperature: 570, 1,
-    HybridSave: 170, 1,
-    ControllerState: 152, 1,
-    ElectronicGearRatioNumerator: 96, 4,
-    ElectronicGearRatioDenominator: 100, 4,
-    InverterTemperatureLimit: 56, 1,
-    PositionLimitThreshold: 38, 2,
-}];
+    ModelNumber: 0, 2, U16, Read, Eeprom [YM070_200_A099_RH = 4050, YM070_200_R051_RH = 4020, YM070_200_R099_RH = 4030, YM070_210_A051_RH = 4040, YM070_210_B001_RH = 4010, YM070_210_M001_RH = 4000, YM080_230_A051_RH = 4160, YM080_230_A099_RH = 4170, YM080_230_B001_RH = 4130, YM080_230_M001_RH = 4120, YM080_230_R051_RH = 4140, YM080_230_R099_RH = 4150],
+    ModelInformation: 2, 4, U32, Read, Eeprom,
+    FirmwareVersion: 6, 1, U8, Read, Eeprom,
+    Id: 7, 1, U8, ReadWrite, Eeprom (0 ~ 252) = 1,
+    BaudRate: 12, 1, U8, ReadWrite, Eeprom (0 ~ 9) = 3,
+    ReturnDelayTime: 13, 1, U8, ReadWrite, Eeprom Time(2e-06) (0 ~ 254) = 0,
+    DriveMode: 32, 1, U8, ReadWrite, Eeprom (0 ~ 255) = 0,
+    OperatingMode: 33, 1, U8, ReadWrite, Eeprom (0 ~ 5) = 3,
+    SecondaryId: 10, 1, U8, ReadWrite, Eeprom (0 ~ 255) = 255,
+    HomingOffset: 52, 4, I32, ReadWrite, Eeprom <YM070_200_A099_RH: Angle(1.21053e-07), YM070_200_R051_RH: Angle(2.34985e-07), YM070_200_R099_RH: Angle(1.21053e-07), YM070_210_A051_RH: Angle(2.34985e-07), YM070_210_B001_RH: Angle(1.19842e-05), YM070_210_M001_RH: Angle(1.19842e-05), YM080_230_A051_RH: Angle(2.34985e-07), YM080_230_A099_RH: Angle(1.21053e-07), YM080_230_B001_RH: Angle(1.19842e-05), YM080_230_M001_RH: Angle(1.19842e-05), YM080_230_R051_RH: Angle(2.34985e-07), YM080_230_R099_RH: Angle(1.21053e-07)> (-2147483648 ~ 2147483647) = 0,
+    MovingThreshold: 48, 4, U32, ReadWrite, Eeprom AngularVelocity(0.0010472) = 10,
+    MotorTemperatureLimit: 57, 1, U8, ReadWrite, Eeprom Temperature(1.0) (0 ~ 120) = 110,
+    MaxVoltageLimit: 60, 2, U16, ReadWrite, Eeprom Voltage(0.1) (150 ~ 400) = 350,
+    MinVoltageLimit: 62, 2, U16, ReadWrite, Eeprom Voltage(0.1) (150 ~ 400) = 150,
+    PwmLimit: 64, 2, U16, ReadWrite, Eeprom Ratio(0.001) (0 ~ 1000) = 1000,
+    CurrentLimit: 66, 2, U16, ReadWrite, Eeprom Current(0.001),
+    AccelerationLimit: 68, 4, U32, ReadWrite, Eeprom AngularAcceleration(0.00174533),
+    VelocityLimit: 72, 4, U32, ReadWrite, Eeprom AngularVelocity(0.0010472),
+    MaxPositionLimit: 76, 4, I32, ReadWrite, Eeprom <YM070_200_A099_RH: Angle(1.21053e-07), YM070_200_R051_RH: Angle(2.34985e-07), YM070_200_R099_RH: Angle(1.21053e-07), YM070_210_A051_RH: Angle(2.34985e-07), YM070_210_B001_RH: Angle(1.19842e-05), YM070_210_M001_RH: Angle(1.19842e-05), YM080_230_A051_RH: Angle(2.34985e-07), YM080_230_A099_RH: Angle(1.21053e-07), YM080_230_B001_RH: Angle(1.19842e-05), YM080_230_M001_RH: Angle(1.19842e-05), YM080_230_R051_RH: Angle(2.34985e-07), YM080_230_R099_RH: Angle(1.21053e-07)> (-2147483648 ~ 2147483647),
+    MinPositionLimit: 84, 4, I32, ReadWrite, Eeprom <YM070_200_A099_RH: Angle(1.21053e-07), YM070_200_R051_RH: Angle(2.34985e-07), YM070_200_R099_RH: Angle(1.21053e-07), YM070_210_A051_RH: Angle(2.34985e-07), YM070_210_B001_RH: Angle(1.19842e-05), YM070_210_M001_RH: Angle(1.19842e-05), YM080_230_A051_RH: Angle(2.34985e-07), YM080_230_A099_RH: Angle(1.21053e-07), YM080_230_B001_RH: Angle(1.19842e-05), YM080_230_M001_RH: Angle(1.19842e-05), YM080_230_R051_RH: Angle(2.34985e-07), YM080_230_R099_RH: Angle(1.21053e-07)> (-2147483648 ~ 2147483647),
+    StartupConfiguration: 34, 1, U8, ReadWrite, Eeprom (0 ~ 255) = 0,
+    TorqueEnable: 512, 1, U8, ReadWrite, Ram (0 ~ 2) = 0,
+    Led: 513, 1, U8, ReadWrite, Ram (0 ~ 1) = 0,
+    StatusReturnLevel: 15, 1, U8, ReadWrite, Eeprom (0 ~ 2) = 2,
+    RegisteredInstruction: 16, 1, U8, Read, Eeprom (0 ~ 1) = 0,
+    VelocityIGain: 212, 4, U32, ReadWrite, Eeprom,
+    VelocityPGain: 216, 4, U32, ReadWrite, Eeprom,
+    PositionPGain: 232, 4, U32, ReadWrite, Eeprom,
+    BusWatchdog: 8, 2, U16, ReadWrite, Eeprom Time(0.02) (0 ~ 65535) = 0,
+    GoalPwm: 524, 2, I16, ReadWrite, Ram Ratio(0.001) (-PwmLimit ~ PwmLimit),
+    GoalCurrent: 526, 2, I16, ReadWrite, Ram Current(0.001) (-CurrentLimit ~ CurrentLimit),
+    GoalVelocity: 528, 4, I32, ReadWrite, Ram AngularVelocity(0.0010472) (-VelocityLimit ~ VelocityLimit),
+    ProfileAcceleration: 240, 4, U32, ReadWrite, Eeprom AngularAcceleration(0.00174533) (0 ~ AccelerationLimit) = 0,
+    ProfileVelocity: 244, 4, U32, ReadWrite, Eeprom AngularVelocity(0.0010472) (0 ~ VelocityLimit) = 0,
+    GoalPosition: 532, 4, I32, ReadWrite, Ram <YM070_200_A099_RH: Angle(1.21053e-07), YM070_200_R051_RH: Angle(2.34985e-07), YM070_200_R099_RH: Angle(1.21053e-07), YM070_210_A051_RH: Angle(2.34985e-07), YM070_210_B001_RH: Angle(1.19842e-05), YM070_210_M001_RH: Angle(1.19842e-05), YM080_230_A051_RH: Angle(2.34985e-07), YM080_230_A099_RH: Angle(1.21053e-07), YM080_230_B001_RH: Angle(1.19842e-05), YM080_230_M001_RH: Angle(1.19842e-05), YM080_230_R051_RH: Angle(2.34985e-07), YM080_230_R099_RH: Angle(1.21053e-07)> (MinPositionLimit ~ MaxPositionLimit),
+    MovingStatus: 541, 1, U8, Read, Ram = 0,
+    PresentPwm: 544, 2, I16, Read, Ram Ratio(0.001),
+    PresentCurrent: 546, 2, I16, Read, Ram Current(0.001),
+    PresentVelocity: 548, 4, I32, Read, Ram AngularVelocity(0.0010472),
+    PresentPosition: 552, 4, I32, Read, Ram <YM070_200_A099_RH: Angle(1.21053e-07), YM070_200_R051_RH: Angle(2.34985e-07), YM070_200_R099_RH: Angle(1.21053e-07), YM070_210_A051_RH: Angle(2.34985e-07), YM070_210_B001_RH: Angle(1.19842e-05), YM070_210_M001_RH: Angle(1.19842e-05), YM080_230_A051_RH: Angle(2.34985e-07), YM080_230_A099_RH: Angle(1.21053e-07), YM080_230_B001_RH: Angle(1.19842e-05), YM080_230_M001_RH: Angle(1.19842e-05), YM080_230_R051_RH: Angle(2.34985e-07), YM080_230_R099_RH: Angle(1.21053e-07)>,
+    VelocityTrajectory: 564, 4, I32, Read, Ram AngularVelocity(0.0010472),
+    PositionTrajectory: 560, 4, I32, Read, Ram <YM070_200_A099_RH: Angle(1.21053e-07), YM070_200_R051_RH: Angle(2.34985e-07), YM070_200_R099_RH: Angle(1.21053e-07), YM070_210_A051_RH: Angle(2.34985e-07), YM070_210_B001_RH: Angle(1.19842e-05), YM070_210_M001_RH: Angle(1.19842e-05), YM080_230_A051_RH: Angle(2.34985e-07), YM080_230_A099_RH: Angle(1.21053e-07), YM080_230_B001_RH: Angle(1.19842e-05), YM080_230_M001_RH: Angle(1.19842e-05), YM080_230_R051_RH: Angle(2.34985e-07), YM080_230_R099_RH: Angle(1.21053e-07)>,
+    PresentInputVoltage: 568, 2, U16, Read, Ram Voltage(0.1),
+    PresentMotorTemperature: 571, 1, U8, Read, Ram Temperature(1.0),
+    InPositionThreshold: 40, 4, U32, ReadWrite, Eeprom <YM070_200_A099_RH: Angle(1.21053e-07), YM070_200_R051_RH: Angle(2.34985e-07), YM070_200_R099_RH: Angle(1.21053e-07), YM070_210_A051_RH: Angle(2.34985e-07), YM070_210_B001_RH: Angle(1.19842e-05), YM070_210_M001_RH: Angle(1.19842e-05), YM080_230_A051_RH: Angle(2.34985e-07), YM080_230_A099_RH: Angle(1.21053e-07), YM080_230_B001_RH: Angle(1.19842e-05), YM080_230_M001_RH: Angle(1.19842e-05), YM080_230_R051_RH: Angle(2.34985e-07), YM080_230_R099_RH: Angle(1.21053e-07)> = 10,
+    FollowingErrorThreshold: 44, 4, U32, ReadWrite, Eeprom <YM070_200_A099_RH: Angle(1.21053e-07), YM070_200_R051_RH: Angle(2.34985e-07), YM070_200_R099_RH: Angle(1.21053e-07), YM070_210_A051_RH: Angle(2.34985e-07), YM070_210_B001_RH: Angle(1.19842e-05), YM070_210_M001_RH: Angle(1.19842e-05), YM080_230_A051_RH: Angle(2.34985e-07), YM080_230_A099_RH: Angle(1.21053e-07), YM080_230_B001_RH: Angle(1.19842e-05), YM080_230_M001_RH: Angle(1.19842e-05), YM080_230_R051_RH: Angle(2.34985e-07), YM080_230_R099_RH: Angle(1.21053e-07)> = 0,
+    SafeStopTime: 104, 2, U16, ReadWrite, Eeprom Time(0.001) = 100,
+    BrakeDelay: 106, 2, U16, ReadWrite, Eeprom Time(0.001) = 0,
+    GoalUpdateDelay: 108, 2, U16, ReadWrite, Eeprom Time(0.001) = 0,
+    OverexcitationVoltage: 110, 1, U8, ReadWrite, Eeprom Voltage(0.1) = 100,
+    NormalExcitationVoltage: 111, 1, U8, ReadWrite, Eeprom Voltage(0.1) = 50,
+    OverexcitationTime: 112, 2, U16, ReadWrite, Eeprom Time(0.001) = 100,
+    PresentVelocityLpfFrequency: 132, 2, U16, ReadWrite, Eeprom = 0,
+    GoalCurrentLpfFrequency: 134, 2, U16, ReadWrite, Eeprom = 0,
+    PositionFfLpfTime: 136, 2, U16, ReadWrite, Eeprom = 0,
+    VelocityFfLpfTime: 138, 2, U16, ReadWrite, Eeprom = 0,
+    ErrorCode: 153, 1, U8, Read, Eeprom = 0,
+    VelocityFfGain: 220, 4, U32, ReadWrite, Eeprom = 0,
+    PositionDGain: 224, 4, U32, ReadWrite, Eeprom = 0,
+    PositionIGain: 228, 4, U32, ReadWrite, Eeprom = 0,
+    PositionFfGain: 236, 4, U32, ReadWrite, Eeprom = 0,
+    ProfileAccelerationTime: 248, 4, U32, ReadWrite, Eeprom Time(0.001) = 0,
+    ProfileTime: 252, 4, U32, ReadWrite, Eeprom Time(0.001) = 0,
+    PwmOffset: 516, 2, I16, ReadWrite, Ram Ratio(0.001) (-PwmLimit ~ PwmLimit) = 0,
+    CurrentOffset: 518, 2, I16, ReadWrite, Ram Current(0.001) (-CurrentLimit ~ CurrentLimit) = 0,
+    VelocityOffset: 520, 4, I32, ReadWrite, Ram AngularVelocity(0.0010472) (-VelocityLimit ~ VelocityLimit) = 0,
+    ProtocolType: 11, 1, U8, ReadWrite, Eeprom (2 ~ 2) = 2,
+    RealtimeTick: 542, 2, U16, Read, Ram Time(0.001),
+    PresentInverterTemperature: 570, 1, U8, Read, Ram Temperature(1.0),
+    HybridSave: 170, 1, U8, ReadWrite, Eeprom (0 ~ 1) = 0,
+    ControllerState: 152, 1, U8, Read, Eeprom = 0,
+    ElectronicGearRatioNumerator: 96, 4, U32, ReadWrite, Eeprom = 1,
+    ElectronicGearRatioDenominator: 100, 4, U32, ReadWrite, Eeprom = 1,
+    InverterTemperatureLimit: 56, 1, U8, ReadWrite, Eeprom Temperature(1.0) (0 ~ 100) = 80,
+    PositionLimitThreshold: 38, 2, U16, ReadWrite, Eeprom = 0,
+} indirect [(256, 634, 128)]];
@@ -0,0 +1,45 @@
+//! The CRC-16 used by protocol 2.0 (polynomial `0x8005`, initial value `0`, not reflected).
+
+const POLYNOMIAL: u16 = 0x8005;
+
+const TABLE: [u16; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = (i as u16) << 8;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ POLYNOMIAL
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Calculate the CRC of a packet, from the header up to (not including) the CRC field.
+pub fn crc16(data: &[u8]) -> u16 {
+    data.iter().fold(0, |crc, byte| {
+        let index = ((crc >> 8) as u8 ^ byte) as usize;
+        (crc << 8) ^ TABLE[index]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::crc16;
+
+    #[test]
+    fn test_crc16() {
+        // Ping ID 1 from the e-Manual
+        assert_eq!(
+            crc16(&[0xFF, 0xFF, 0xFD, 0x00, 0x01, 0x03, 0x00, 0x01]),
+            0x4E19
+        );
+    }
+}
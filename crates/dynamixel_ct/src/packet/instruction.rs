@@ -0,0 +1,282 @@
+//! Building instruction packets.
+
+use dynamixel_registers::{Register, Value, ValueError};
+
+use super::{check_protocol, crc16, PacketError, BROADCAST_ID, HEADER};
+use crate::ModelTable;
+
+/// The instruction of a packet
+#[derive(Clone, Copy, Debug, PartialEq, Eq, derive_more::Display)]
+#[repr(u8)]
+pub enum Instruction {
+    /// Check the servo with the ID is present
+    Ping = 0x01,
+    /// Read a span of the control table
+    Read = 0x02,
+    /// Write a span of the control table
+    Write = 0x03,
+    /// Write a span of the control table when [`Instruction::Action`] is received
+    RegWrite = 0x04,
+    /// Execute a [`Instruction::RegWrite`]
+    Action = 0x05,
+    /// Reset the control table to the factory defaults
+    FactoryReset = 0x06,
+    /// Reboot the servo
+    Reboot = 0x08,
+    /// Clear the multi-turn information or errors
+    Clear = 0x10,
+    /// Back up or restore the control table
+    ControlTableBackup = 0x20,
+    /// The response to an instruction
+    Status = 0x55,
+    /// Read the same span from several servos
+    SyncRead = 0x82,
+    /// Write the same span of several servos
+    SyncWrite = 0x83,
+    /// [`Instruction::SyncRead`] with a single combined status packet
+    FastSyncRead = 0x8A,
+    /// Read a different span from several servos
+    BulkRead = 0x92,
+    /// Write a different span of several servos
+    BulkWrite = 0x93,
+    /// [`Instruction::BulkRead`] with a single combined status packet
+    FastBulkRead = 0x9A,
+}
+
+/// Writes a packet into a buffer, adding byte stuffing, the length and the CRC.
+///
+/// ```rust
+/// use dynamixel_ct::packet::{Instruction, PacketWriter};
+/// let mut buffer = [0; 16];
+/// let mut writer = PacketWriter::new(&mut buffer, 1, Instruction::Read).unwrap();
+/// writer.extend(&[0x84, 0x00, 0x04, 0x00]).unwrap();
+/// let length = writer.finish().unwrap();
+/// assert_eq!(buffer[..length], [0xFF, 0xFF, 0xFD, 0x00, 0x01, 0x07, 0x00, 0x02, 0x84, 0x00, 0x04, 0x00, 0x1D, 0x15]);
+/// ```
+#[derive(Debug)]
+pub struct PacketWriter<'a> {
+    buffer: &'a mut [u8],
+    length: usize,
+}
+
+/// The index of the instruction, where byte stuffing starts
+const INSTRUCTION_INDEX: usize = 7;
+
+impl<'a> PacketWriter<'a> {
+    /// Start a packet for the servo `id` in `buffer`
+    pub fn new(
+        buffer: &'a mut [u8],
+        id: u8,
+        instruction: Instruction,
+    ) -> Result<Self, PacketError> {
+        let mut writer = Self { buffer, length: 0 };
+        for byte in HEADER {
+            writer.write(byte)?;
+        }
+        // the length is written by `finish`
+        writer.write(id)?;
+        writer.write(0)?;
+        writer.write(0)?;
+        writer.push(instruction as u8)?;
+        Ok(writer)
+    }
+
+    fn write(&mut self, byte: u8) -> Result<(), PacketError> {
+        let available = self.buffer.len();
+        *self
+            .buffer
+            .get_mut(self.length)
+            .ok_or(PacketError::BufferTooSmall { available })? = byte;
+        self.length += 1;
+        Ok(())
+    }
+
+    /// Add a parameter byte, stuffing an extra `0xFD` after any `0xFF 0xFF 0xFD` so it can't be mistaken for a header
+    pub fn push(&mut self, byte: u8) -> Result<(), PacketError> {
+        self.write(byte)?;
+        if self.length >= INSTRUCTION_INDEX + 3
+            && self.buffer[self.length - 3..self.length] == HEADER[..3]
+        {
+            self.write(0xFD)?;
+        }
+        Ok(())
+    }
+
+    /// Add parameter bytes
+    pub fn extend(&mut self, bytes: &[u8]) -> Result<(), PacketError> {
+        bytes.iter().try_for_each(|byte| self.push(*byte))
+    }
+
+    /// Add the little-endian bytes of a value
+    pub fn push_value(&mut self, value: Value) -> Result<(), PacketError> {
+        let mut bytes = [0; 4];
+        let length = value.value_type().length() as usize;
+        value.encode(&mut bytes[..length])?;
+        self.extend(&bytes[..length])
+    }
+
+    /// Write the length and CRC, returning the length of the packet
+    pub fn finish(mut self) -> Result<usize, PacketError> {
+        let length = (self.length - INSTRUCTION_INDEX + 2) as u16;
+        self.buffer[5..7].copy_from_slice(&length.to_le_bytes());
+        let crc = crc16(&self.buffer[..self.length]);
+        for byte in crc.to_le_bytes() {
+            self.write(byte)?;
+        }
+        Ok(self.length)
+    }
+}
+
+/// Write a packet with the given parameters into `buffer`, returning the length of the packet
+pub fn instruction(
+    buffer: &mut [u8],
+    id: u8,
+    instruction: Instruction,
+    params: &[u8],
+) -> Result<usize, PacketError> {
+    let mut writer = PacketWriter::new(buffer, id, instruction)?;
+    writer.extend(params)?;
+    writer.finish()
+}
+
+/// Write a [`Instruction::Ping`] packet into `buffer`, returning the length of the packet
+pub fn ping(buffer: &mut [u8], id: u8) -> Result<usize, PacketError> {
+    instruction(buffer, id, Instruction::Ping, &[])
+}
+
+/// Write a [`Instruction::Read`] packet for a register into `buffer`, returning the length of the packet.
+/// The address and length are taken from `table`.
+/// Returns [`RegisterError::UnsupportedProtocol`](crate::control_table::RegisterError::UnsupportedProtocol)
+/// if the model doesn't speak protocol 2.0.
+pub fn read(
+    buffer: &mut [u8],
+    id: u8,
+    register: Register,
+    table: &impl ModelTable,
+) -> Result<usize, PacketError> {
+    check_protocol(table)?;
+    let data = table.get(register)?;
+    read_span(buffer, id, data.address, data.length)
+}
+
+/// Write a [`Instruction::Read`] packet for a span of addresses into `buffer`, returning the length of the packet.
+/// The servo must speak protocol 2.0, see [`ModelTable::protocol`].
+pub fn read_span(
+    buffer: &mut [u8],
+    id: u8,
+    address: u16,
+    length: u16,
+) -> Result<usize, PacketError> {
+    let mut writer = PacketWriter::new(buffer, id, Instruction::Read)?;
+    writer.extend(&address.to_le_bytes())?;
+    writer.extend(&length.to_le_bytes())?;
+    writer.finish()
+}
+
+/// Write a [`Instruction::Write`] packet for a register into `buffer`, returning the length of the packet.
+/// The address is taken from `table`, and the value must be writable and of the register's value type.
+/// Returns [`RegisterError::UnsupportedProtocol`](crate::control_table::RegisterError::UnsupportedProtocol)
+/// if the model doesn't speak protocol 2.0.
+pub fn write(
+    buffer: &mut [u8],
+    id: u8,
+    register: Register,
+    value: impl Into<Value>,
+    table: &impl ModelTable,
+) -> Result<usize, PacketError> {
+    check_protocol(table)?;
+    let data = table.get_writable(register)?;
+    let value = value.into();
+    if value.value_type() != data.value_type {
+        return Err(ValueError::TypeMismatch {
+            expected: data.value_type,
+            actual: value.value_type(),
+        }
+        .into());
+    }
+    let mut writer = PacketWriter::new(buffer, id, Instruction::Write)?;
+    writer.extend(&data.address.to_le_bytes())?;
+    writer.push_value(value)?;
+    writer.finish()
+}
+
+/// The span of the control table of one servo in a [`Instruction::BulkRead`] or [`Instruction::BulkWrite`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ServoSpan {
+    /// The ID of the servo
+    pub id: u8,
+    /// The address of the start of the span
+    pub address: u16,
+    /// The number of bytes in the span
+    pub length: u16,
+}
+
+/// Write a [`Instruction::SyncRead`] packet for the same span of several servos into `buffer`, returning the length of the packet
+pub fn sync_read(
+    buffer: &mut [u8],
+    address: u16,
+    length: u16,
+    ids: &[u8],
+) -> Result<usize, PacketError> {
+    let mut writer = PacketWriter::new(buffer, BROADCAST_ID, Instruction::SyncRead)?;
+    writer.extend(&address.to_le_bytes())?;
+    writer.extend(&length.to_le_bytes())?;
+    writer.extend(ids)?;
+    writer.finish()
+}
+
+/// Write a [`Instruction::BulkRead`] packet for a different span of each servo into `buffer`, returning the length of the packet
+pub fn bulk_read(buffer: &mut [u8], reads: &[ServoSpan]) -> Result<usize, PacketError> {
+    let mut writer = PacketWriter::new(buffer, BROADCAST_ID, Instruction::BulkRead)?;
+    for read in reads {
+        writer.push(read.id)?;
+        writer.extend(&read.address.to_le_bytes())?;
+        writer.extend(&read.length.to_le_bytes())?;
+    }
+    writer.finish()
+}
+
+/// Write a [`Instruction::SyncWrite`] packet for the same span of several servos into `buffer`, returning the length of the packet.
+/// Returns [`ValueError::LengthMismatch`] if the data of a servo is not `length` bytes.
+pub fn sync_write(
+    buffer: &mut [u8],
+    address: u16,
+    length: u16,
+    data: &[(u8, &[u8])],
+) -> Result<usize, PacketError> {
+    let mut writer = PacketWriter::new(buffer, BROADCAST_ID, Instruction::SyncWrite)?;
+    writer.extend(&address.to_le_bytes())?;
+    writer.extend(&length.to_le_bytes())?;
+    for (id, bytes) in data {
+        check_length(length, bytes)?;
+        writer.push(*id)?;
+        writer.extend(bytes)?;
+    }
+    writer.finish()
+}
+
+/// Write a [`Instruction::BulkWrite`] packet for a different span of each servo into `buffer`, returning the length of the packet.
+/// Returns [`ValueError::LengthMismatch`] if the data of a servo is not the length of its span.
+pub fn bulk_write(buffer: &mut [u8], data: &[(ServoSpan, &[u8])]) -> Result<usize, PacketError> {
+    let mut writer = PacketWriter::new(buffer, BROADCAST_ID, Instruction::BulkWrite)?;
+    for (span, bytes) in data {
+        check_length(span.length, bytes)?;
+        writer.push(span.id)?;
+        writer.extend(&span.address.to_le_bytes())?;
+        writer.extend(&span.length.to_le_bytes())?;
+        writer.extend(bytes)?;
+    }
+    writer.finish()
+}
+
+fn check_length(length: u16, bytes: &[u8]) -> Result<(), PacketError> {
+    if bytes.len() == length as usize {
+        Ok(())
+    } else {
+        Err(ValueError::LengthMismatch {
+            expected: length,
+            actual: bytes.len(),
+        }
+        .into())
+    }
+}
@@ -0,0 +1,321 @@
+//! Protocol 2.0 packets.
+//!
+//! Instruction packets are written into a caller provided buffer and status packets are parsed in place,
+//! so this module can be used without `std`.
+//! The address and length of a register are taken from a [`ModelTable`].
+//! Models that speak protocol 1.0 (see [`ModelTable::protocol`]) can't be used with these packets,
+//! the functions taking a table return [`RegisterError::UnsupportedProtocol`] for them.
+//!
+//! ## Example
+//! ```rust
+//! use dynamixel_ct::packet::{self, StatusPacket};
+//! use dynamixel_ct::{ControlTable, Model, Register, Value};
+//!
+//! let control_table = ControlTable::new_with_model(Model::XM430_W350);
+//! let mut buffer = [0; 32];
+//! let length = packet::read(&mut buffer, 1, Register::PresentPosition, &control_table).unwrap();
+//! // send &buffer[..length] and receive the response
+//! let mut response = [0xFF, 0xFF, 0xFD, 0x00, 0x01, 0x08, 0x00, 0x55, 0x00, 0xA6, 0x00, 0x00, 0x00, 0x8C, 0xC0];
+//! let (status, _) = StatusPacket::parse(&mut response).unwrap();
+//! assert_eq!(status.value(Register::PresentPosition, &control_table), Ok(Value::I32(166)));
+//! ```
+
+mod crc;
+mod instruction;
+mod status;
+
+pub use crc::crc16;
+pub use instruction::{
+    bulk_read, bulk_write, instruction, ping, read, read_span, sync_read, sync_write, write,
+    Instruction, PacketWriter, ServoSpan,
+};
+pub use status::{StatusError, StatusPacket};
+
+use dynamixel_registers::models::Protocol;
+use dynamixel_registers::{Register, ValueError};
+
+use crate::control_table::RegisterError;
+use crate::ModelTable;
+
+/// Check the packets of this module can be used with the model of `table`.
+/// Returns [`RegisterError::UnsupportedProtocol`] if the model doesn't speak protocol 2.0.
+pub(crate) fn check_protocol(table: &(impl ModelTable + ?Sized)) -> Result<(), RegisterError> {
+    match table.protocol() {
+        Protocol::V2 => Ok(()),
+        protocol => Err(RegisterError::UnsupportedProtocol {
+            model_or_group: table.model_or_group(),
+            protocol,
+        }),
+    }
+}
+
+/// The header at the start of every packet
+pub const HEADER: [u8; 4] = [0xFF, 0xFF, 0xFD, 0x00];
+
+/// The ID that addresses every servo on the bus
+pub const BROADCAST_ID: u8 = 0xFE;
+
+/// An error writing or parsing a packet
+#[derive(Clone, Debug, PartialEq, derive_more::Display, derive_more::Error, derive_more::From)]
+pub enum PacketError {
+    /// The packet does not fit in the buffer
+    #[display("packet does not fit in a buffer of {available} bytes")]
+    #[from(ignore)]
+    BufferTooSmall {
+        /// The length of the buffer
+        available: usize,
+    },
+    /// The buffer does not contain a packet header
+    #[display("no packet header found")]
+    NoHeader,
+    /// The buffer ends before the end of the packet
+    #[display("packet is {required} bytes but only {available} were received")]
+    #[from(ignore)]
+    Incomplete {
+        /// The length of the packet
+        required: usize,
+        /// The number of bytes received
+        available: usize,
+    },
+    /// The length field is too short for a status packet
+    #[display("invalid packet length")]
+    InvalidLength,
+    /// The CRC of the packet does not match its contents
+    #[display("CRC mismatch, expected {expected:#06X} but got {actual:#06X}")]
+    #[from(ignore)]
+    CrcMismatch {
+        /// The CRC calculated from the packet
+        expected: u16,
+        /// The CRC in the packet
+        actual: u16,
+    },
+    /// A packet other than a status packet was received
+    #[display("expected a status packet but got instruction {instruction:#04X}")]
+    #[from(ignore)]
+    NotStatus {
+        /// The instruction of the packet
+        instruction: u8,
+    },
+    /// The servo responded with an error
+    #[display("servo {id} responded with {error}")]
+    #[from(ignore)]
+    Status {
+        /// The ID of the servo
+        id: u8,
+        /// The error of the status packet
+        error: StatusError,
+    },
+    /// The status packet does not contain the register
+    #[display("status packet does not contain {register} register")]
+    #[from(ignore)]
+    MissingData {
+        /// The register requested
+        register: Register,
+    },
+    /// A write packet was requested without the data to write
+    #[display("write packets need the data to write")]
+    NoWriteData,
+    /// The register cannot be used with the model
+    #[display("{_0}")]
+    Register(RegisterError),
+    /// The value cannot be encoded or decoded
+    #[display("{_0}")]
+    Value(ValueError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::XM430;
+    use crate::{ControlTable, Model, Value};
+
+    #[test]
+    fn test_instruction_packets() {
+        let mut buffer = [0; 16];
+        let length = ping(&mut buffer, 1).unwrap();
+        assert_eq!(
+            buffer[..length],
+            [0xFF, 0xFF, 0xFD, 0x00, 0x01, 0x03, 0x00, 0x01, 0x19, 0x4E]
+        );
+
+        let length = read(&mut buffer, 1, Register::PresentPosition, &XM430).unwrap();
+        assert_eq!(
+            buffer[..length],
+            [0xFF, 0xFF, 0xFD, 0x00, 0x01, 0x07, 0x00, 0x02, 0x84, 0x00, 0x04, 0x00, 0x1D, 0x15]
+        );
+
+        let control_table = ControlTable::new_with_model(Model::XM430_W350);
+        let length = write(
+            &mut buffer,
+            1,
+            Register::GoalPosition,
+            512i32,
+            &control_table,
+        )
+        .unwrap();
+        assert_eq!(
+            buffer[..length],
+            [
+                0xFF, 0xFF, 0xFD, 0x00, 0x01, 0x09, 0x00, 0x03, 0x74, 0x00, 0x00, 0x02, 0x00, 0x00,
+                0xCA, 0x89
+            ]
+        );
+
+        assert!(matches!(
+            write(
+                &mut buffer,
+                1,
+                Register::PresentPosition,
+                512i32,
+                &control_table
+            ),
+            Err(PacketError::Register(RegisterError::ReadOnly { .. }))
+        ));
+        assert!(matches!(
+            write(
+                &mut buffer,
+                1,
+                Register::GoalPosition,
+                512u16,
+                &control_table
+            ),
+            Err(PacketError::Value(ValueError::TypeMismatch { .. }))
+        ));
+        assert_eq!(
+            ping(&mut buffer[..8], 1),
+            Err(PacketError::BufferTooSmall { available: 8 })
+        );
+    }
+
+    #[test]
+    fn test_sync_packets() {
+        let mut buffer = [0; 32];
+        let length = sync_read(&mut buffer, 132, 4, &[1, 2]).unwrap();
+        assert_eq!(
+            buffer[..length],
+            [
+                0xFF, 0xFF, 0xFD, 0x00, 0xFE, 0x09, 0x00, 0x82, 0x84, 0x00, 0x04, 0x00, 0x01, 0x02,
+                0xCE, 0xFA
+            ]
+        );
+
+        let length = sync_write(
+            &mut buffer,
+            116,
+            4,
+            &[(1, &150u32.to_le_bytes()), (2, &170u32.to_le_bytes())],
+        )
+        .unwrap();
+        assert_eq!(
+            buffer[..length],
+            [
+                0xFF, 0xFF, 0xFD, 0x00, 0xFE, 0x11, 0x00, 0x83, 0x74, 0x00, 0x04, 0x00, 0x01, 0x96,
+                0x00, 0x00, 0x00, 0x02, 0xAA, 0x00, 0x00, 0x00, 0x82, 0x87
+            ]
+        );
+        assert_eq!(
+            sync_write(&mut buffer, 116, 4, &[(1, &[0x96])]),
+            Err(PacketError::Value(ValueError::LengthMismatch {
+                expected: 4,
+                actual: 1
+            }))
+        );
+    }
+
+    #[test]
+    fn test_status_packet() {
+        let mut buffer = [
+            0x00, 0xFF, 0xFF, 0xFD, 0x00, 0x01, 0x08, 0x00, 0x55, 0x00, 0xA6, 0x00, 0x00, 0x00,
+            0x8C, 0xC0,
+        ];
+        let (status, rest) = StatusPacket::parse(&mut buffer).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(status.id, 1);
+        assert_eq!(status.error, None);
+        assert_eq!(
+            status.value(Register::PresentPosition, &XM430),
+            Ok(Value::I32(166))
+        );
+        assert_eq!(
+            status.value_at(132, Register::PresentVelocity, &XM430),
+            Err(PacketError::MissingData {
+                register: Register::PresentVelocity
+            })
+        );
+
+        let mut buffer = [
+            0xFF, 0xFF, 0xFD, 0x00, 0x01, 0x08, 0x00, 0x55, 0x00, 0xA6, 0x00, 0x00, 0x00, 0x8C,
+            0xC1,
+        ];
+        assert_eq!(
+            StatusPacket::parse(&mut buffer),
+            Err(PacketError::CrcMismatch {
+                expected: 0xC08C,
+                actual: 0xC18C
+            })
+        );
+    }
+
+    #[test]
+    fn test_byte_stuffing() {
+        let mut buffer = [0; 32];
+        // a status packet with no error, whose parameters contain a header
+        let params = [0x00, 0xFF, 0xFF, 0xFD, 0xFD, 0x01];
+        let length = instruction(&mut buffer, 2, Instruction::Status, &params).unwrap();
+        assert_eq!(
+            buffer[7..length - 2],
+            [0x55, 0x00, 0xFF, 0xFF, 0xFD, 0xFD, 0xFD, 0x01]
+        );
+        assert_eq!(buffer[5..7], 10u16.to_le_bytes());
+
+        let (status, _) = StatusPacket::parse(&mut buffer[..length]).unwrap();
+        assert_eq!(status.id, 2);
+        assert_eq!(status.params, [0xFF, 0xFF, 0xFD, 0xFD, 0x01]);
+    }
+
+    #[test]
+    fn test_status_error() {
+        let mut buffer = [0; 16];
+        // data range error with the alert bit set
+        let length = instruction(&mut buffer, 1, Instruction::Status, &[0x84]).unwrap();
+        let (status, _) = StatusPacket::parse(&mut buffer[..length]).unwrap();
+        assert_eq!(status.error, Some(StatusError::DataRangeError));
+        assert!(status.alert);
+        assert_eq!(
+            status.value(Register::PresentPosition, &XM430),
+            Err(PacketError::Status {
+                id: 1,
+                error: StatusError::DataRangeError
+            })
+        );
+    }
+
+    #[test]
+    fn test_protocol_1() {
+        let control_table = ControlTable::new(crate::ModelGroup::AX12);
+        let error = PacketError::Register(RegisterError::UnsupportedProtocol {
+            model_or_group: crate::ModelGroup::AX12.into(),
+            protocol: Protocol::V1,
+        });
+        let mut buffer = [0; 16];
+        assert_eq!(
+            read(&mut buffer, 1, Register::PresentPosition, &control_table),
+            Err(error.clone())
+        );
+        assert_eq!(
+            write(&mut buffer, 1, Register::Led, Value::U8(1), &control_table),
+            Err(error.clone())
+        );
+        assert_eq!(
+            error.to_string(),
+            "AX12 uses protocol 1.0, only protocol 2.0 is supported"
+        );
+
+        let length = instruction(&mut buffer, 1, Instruction::Status, &[0x00, 0x01]).unwrap();
+        let (status, _) = StatusPacket::parse(&mut buffer[..length]).unwrap();
+        assert_eq!(
+            status.value_at(25, Register::Led, &control_table),
+            Err(error)
+        );
+    }
+}
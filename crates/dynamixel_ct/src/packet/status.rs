@@ -0,0 +1,180 @@
+//! Parsing status packets.
+
+use dynamixel_registers::{Register, Value};
+
+use super::{check_protocol, crc16, Instruction, PacketError, HEADER};
+use crate::ModelTable;
+
+/// The error number of a status packet
+#[derive(Clone, Copy, Debug, PartialEq, Eq, derive_more::Display)]
+pub enum StatusError {
+    /// The instruction failed
+    #[display("result fail")]
+    ResultFail,
+    /// The instruction is undefined, or an [`Instruction::Action`] was sent without a [`Instruction::RegWrite`]
+    #[display("instruction error")]
+    InstructionError,
+    /// The CRC of the instruction packet does not match
+    #[display("CRC error")]
+    CrcError,
+    /// The data written is out of the range of the register
+    #[display("data range error")]
+    DataRangeError,
+    /// The data written is shorter than the register
+    #[display("data length error")]
+    DataLengthError,
+    /// The data written is outside the limits of the register
+    #[display("data limit error")]
+    DataLimitError,
+    /// The register can't be written, or is locked while torque is enabled
+    #[display("access error")]
+    AccessError,
+    /// An error number not defined by the protocol
+    #[display("unknown error {_0}")]
+    Unknown(u8),
+}
+
+impl StatusError {
+    /// Get the error from the error field of a status packet, ignoring the alert bit.
+    /// Returns `None` if there is no error.
+    pub const fn from_error_field(error: u8) -> Option<Self> {
+        let error = match error & 0x7F {
+            0x00 => return None,
+            0x01 => StatusError::ResultFail,
+            0x02 => StatusError::InstructionError,
+            0x03 => StatusError::CrcError,
+            0x04 => StatusError::DataRangeError,
+            0x05 => StatusError::DataLengthError,
+            0x06 => StatusError::DataLimitError,
+            0x07 => StatusError::AccessError,
+            error => StatusError::Unknown(error),
+        };
+        Some(error)
+    }
+}
+
+/// A parsed status packet, with the byte stuffing removed from the parameters
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StatusPacket<'a> {
+    /// The ID of the servo that sent the packet
+    pub id: u8,
+    /// The error of the instruction, if any
+    pub error: Option<StatusError>,
+    /// The alert bit is set when the servo has a hardware error, see [`Register::HardwareErrorStatus`]
+    pub alert: bool,
+    /// The parameters of the packet
+    pub params: &'a [u8],
+}
+
+impl<'a> StatusPacket<'a> {
+    /// Parse the first status packet in `buffer`, skipping any bytes before the header.
+    /// The byte stuffing is removed in place.
+    /// Returns the packet and the rest of `buffer` after it. The packet only borrows its own bytes,
+    /// so several packets (e.g. the responses to a [`Instruction::SyncRead`]) can be parsed from one buffer
+    /// by parsing the rest again.
+    ///
+    /// ```rust
+    /// use dynamixel_ct::packet::{instruction, Instruction, StatusPacket};
+    /// // the responses of two servos to a Sync Read, received into one buffer
+    /// let mut buffer = [0; 32];
+    /// let first = instruction(&mut buffer, 1, Instruction::Status, &[0x00, 0x2A]).unwrap();
+    /// let second = instruction(&mut buffer[first..], 2, Instruction::Status, &[0x00, 0x2B]).unwrap();
+    ///
+    /// let (status_1, rest) = StatusPacket::parse(&mut buffer[..first + second]).unwrap();
+    /// let (status_2, rest) = StatusPacket::parse(rest).unwrap();
+    /// assert_eq!((status_1.id, status_1.params), (1, &[0x2A][..]));
+    /// assert_eq!((status_2.id, status_2.params), (2, &[0x2B][..]));
+    /// assert!(rest.is_empty());
+    /// ```
+    pub fn parse(buffer: &'a mut [u8]) -> Result<(Self, &'a mut [u8]), PacketError> {
+        let start = buffer
+            .windows(HEADER.len())
+            .position(|window| window == HEADER)
+            .ok_or(PacketError::NoHeader)?;
+        let (_, buffer) = buffer.split_at_mut(start);
+        if buffer.len() < 7 {
+            return Err(PacketError::Incomplete {
+                required: 7,
+                available: buffer.len(),
+            });
+        }
+        let id = buffer[4];
+        let total = 7 + u16::from_le_bytes([buffer[5], buffer[6]]) as usize;
+        // the instruction, error and CRC
+        if total < 7 + 4 {
+            return Err(PacketError::InvalidLength);
+        }
+        if buffer.len() < total {
+            return Err(PacketError::Incomplete {
+                required: total,
+                available: buffer.len(),
+            });
+        }
+        let expected = crc16(&buffer[..total - 2]);
+        let actual = u16::from_le_bytes([buffer[total - 2], buffer[total - 1]]);
+        if expected != actual {
+            return Err(PacketError::CrcMismatch { expected, actual });
+        }
+        if buffer[7] != Instruction::Status as u8 {
+            return Err(PacketError::NotStatus {
+                instruction: buffer[7],
+            });
+        }
+        let (packet, rest) = buffer.split_at_mut(total);
+        let length = unstuff(&mut packet[7..total - 2]);
+        let packet: &'a [u8] = packet;
+        let error = packet[8];
+        let packet = StatusPacket {
+            id,
+            error: StatusError::from_error_field(error),
+            alert: error & 0x80 != 0,
+            params: &packet[9..7 + length],
+        };
+        Ok((packet, rest))
+    }
+
+    /// Decode the value of a register from the response to a [`Instruction::Read`] of the register.
+    pub fn value(&self, register: Register, table: &impl ModelTable) -> Result<Value, PacketError> {
+        self.value_at(table.get(register)?.address, register, table)
+    }
+
+    /// Decode the value of a register from the response to a read of a span starting at `address`.
+    /// Returns [`PacketError::Status`] if the packet has an error,
+    /// [`PacketError::MissingData`] if the register is not within the parameters,
+    /// and [`RegisterError::UnsupportedProtocol`](crate::control_table::RegisterError::UnsupportedProtocol)
+    /// if the model doesn't speak protocol 2.0.
+    pub fn value_at(
+        &self,
+        address: u16,
+        register: Register,
+        table: &impl ModelTable,
+    ) -> Result<Value, PacketError> {
+        check_protocol(table)?;
+        if let Some(error) = self.error {
+            return Err(PacketError::Status { id: self.id, error });
+        }
+        let data = table.get(register)?;
+        let bytes = (data.address as usize)
+            .checked_sub(address as usize)
+            .and_then(|start| self.params.get(start..start + data.length as usize))
+            .ok_or(PacketError::MissingData { register })?;
+        Ok(data.decode(bytes)?)
+    }
+}
+
+/// Remove the byte stuffing in place, returning the new length
+fn unstuff(bytes: &mut [u8]) -> usize {
+    let mut length = 0;
+    let mut stuffed = false;
+    for i in 0..bytes.len() {
+        // only the first 0xFD after 0xFF 0xFF 0xFD is stuffing
+        if !stuffed && length >= 3 && bytes[length - 3..length] == HEADER[..3] && bytes[i] == 0xFD {
+            stuffed = true;
+            continue;
+        }
+        stuffed = false;
+        bytes[length] = bytes[i];
+        length += 1;
+    }
+    length
+}
@@ -0,0 +1,486 @@
+//! Plan the Sync Read, Bulk Read, Sync Write and Bulk Write transactions for a chain of servos with different models.
+//!
+//! A Sync Read or Sync Write uses one address and length for every servo, but the same register can be at
+//! a different address on each model (e.g. [`Register::GoalPosition`] is at 116 on the X series and 564 on the P series).
+//! Servos whose spans agree are grouped into one sync transaction, and the rest are combined into bulk transactions,
+//! unless reading or writing every servo with bulk transactions takes fewer transactions.
+//! Sync and Bulk instructions are protocol 2.0 only, so every servo must speak protocol 2.0.
+//!
+//! ## Example
+//! ```rust
+//! use dynamixel_ct::plan::{plan_read, Transaction};
+//! use dynamixel_ct::{ControlTable, Model, Register};
+//!
+//! let servos = [
+//!     (1, ControlTable::new_with_model(Model::XM430_W350)),
+//!     (2, ControlTable::new_with_model(Model::XL430_W250)),
+//!     (3, ControlTable::new_with_model(Model::PH54_200_S500_R)),
+//! ];
+//! let plan = plan_read(&servos, &[Register::GoalPosition, Register::PresentPosition]).unwrap();
+//! // one Bulk Read instead of a Sync Read of the X series and another of the P series
+//! assert_eq!(plan.len(), 1);
+//! assert!(matches!(plan[0], Transaction::BulkRead { .. }));
+//! assert_eq!(plan[0].spans().len(), 3);
+//! ```
+
+use std::collections::BTreeMap;
+
+use dynamixel_registers::Register;
+
+use crate::control_table::RegisterError;
+use crate::packet::{self, check_protocol, PacketError, ServoSpan};
+use crate::{ControlTable, ModelTable};
+
+/// A transaction on the bus, planned by [`plan_read`] or [`plan_write`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Transaction {
+    /// Read the same span of several servos
+    SyncRead {
+        /// The address of the start of the span
+        address: u16,
+        /// The number of bytes in the span
+        length: u16,
+        /// The IDs of the servos
+        ids: Vec<u8>,
+    },
+    /// Read a different span of each servo
+    BulkRead {
+        /// The span of each servo
+        reads: Vec<ServoSpan>,
+    },
+    /// Write the same span of several servos
+    SyncWrite {
+        /// The address of the start of the span
+        address: u16,
+        /// The number of bytes in the span
+        length: u16,
+        /// The IDs of the servos
+        ids: Vec<u8>,
+    },
+    /// Write a different span of each servo
+    BulkWrite {
+        /// The span of each servo
+        writes: Vec<ServoSpan>,
+    },
+}
+
+impl Transaction {
+    /// The span of each servo in this transaction
+    pub fn spans(&self) -> Vec<ServoSpan> {
+        match self {
+            Transaction::SyncRead {
+                address,
+                length,
+                ids,
+            }
+            | Transaction::SyncWrite {
+                address,
+                length,
+                ids,
+            } => ids
+                .iter()
+                .map(|id| ServoSpan {
+                    id: *id,
+                    address: *address,
+                    length: *length,
+                })
+                .collect(),
+            Transaction::BulkRead { reads: spans } | Transaction::BulkWrite { writes: spans } => {
+                spans.clone()
+            }
+        }
+    }
+
+    /// Write the instruction packet of a [`Transaction::SyncRead`] or [`Transaction::BulkRead`] into `buffer`,
+    /// returning the length of the packet.
+    /// Returns [`PacketError::NoWriteData`] for a write transaction, as its packet needs the data to write,
+    /// see [`packet::sync_write`] and [`packet::bulk_write`].
+    pub fn read_packet(&self, buffer: &mut [u8]) -> Result<usize, PacketError> {
+        match self {
+            Transaction::SyncRead {
+                address,
+                length,
+                ids,
+            } => packet::sync_read(buffer, *address, *length, ids),
+            Transaction::BulkRead { reads } => packet::bulk_read(buffer, reads),
+            Transaction::SyncWrite { .. } | Transaction::BulkWrite { .. } => {
+                Err(PacketError::NoWriteData)
+            }
+        }
+    }
+}
+
+/// Plan the transactions to read `registers` from every servo.
+///
+/// Each servo reads one span covering all the registers. Servos with the same span are read with
+/// a [`Transaction::SyncRead`], and the rest with a [`Transaction::BulkRead`],
+/// unless one [`Transaction::BulkRead`] of every servo takes fewer transactions.
+/// Returns [`RegisterError::Missing`] if a servo doesn't have one of the registers,
+/// and [`RegisterError::UnsupportedProtocol`] if a servo doesn't speak protocol 2.0.
+pub fn plan_read(
+    servos: &[(u8, ControlTable)],
+    registers: &[Register],
+) -> Result<Vec<Transaction>, RegisterError> {
+    let mut spans = Vec::new();
+    for (id, control_table) in servos {
+        check_protocol(control_table)?;
+        if let Some((address, length)) = covering_span(control_table, registers)? {
+            spans.push(ServoSpan {
+                id: *id,
+                address,
+                length,
+            });
+        }
+    }
+    Ok(group(
+        spans,
+        |address, length, ids| Transaction::SyncRead {
+            address,
+            length,
+            ids,
+        },
+        |reads| Transaction::BulkRead { reads },
+    ))
+}
+
+/// Plan the transactions to write `registers` of every servo.
+///
+/// Only the registers requested are written, so registers that are not next to each other are written in separate spans.
+/// Servos with the same span are written with a [`Transaction::SyncWrite`], and the rest with a [`Transaction::BulkWrite`],
+/// unless writing every servo with [`Transaction::BulkWrite`]s takes fewer transactions.
+/// Returns [`RegisterError::Missing`] if a servo doesn't have one of the registers,
+/// [`RegisterError::ReadOnly`] if one of the registers can't be written,
+/// and [`RegisterError::UnsupportedProtocol`] if a servo doesn't speak protocol 2.0.
+pub fn plan_write(
+    servos: &[(u8, ControlTable)],
+    registers: &[Register],
+) -> Result<Vec<Transaction>, RegisterError> {
+    let mut spans = Vec::new();
+    for (id, control_table) in servos {
+        check_protocol(control_table)?;
+        let mut data = registers
+            .iter()
+            .map(|register| control_table.get_writable(*register))
+            .collect::<Result<Vec<_>, _>>()?;
+        data.sort_by_key(|data| data.address);
+        data.dedup();
+        let mut current: Option<ServoSpan> = None;
+        for data in data {
+            match &mut current {
+                Some(span) if span.address + span.length == data.address => {
+                    span.length += data.length;
+                }
+                _ => {
+                    spans.extend(current);
+                    current = Some(ServoSpan {
+                        id: *id,
+                        address: data.address,
+                        length: data.length,
+                    });
+                }
+            }
+        }
+        spans.extend(current);
+    }
+    Ok(group(
+        spans,
+        |address, length, ids| Transaction::SyncWrite {
+            address,
+            length,
+            ids,
+        },
+        |writes| Transaction::BulkWrite { writes },
+    ))
+}
+
+/// The span from the first to the end of the last register, or `None` if there are no registers
+fn covering_span(
+    control_table: &ControlTable,
+    registers: &[Register],
+) -> Result<Option<(u16, u16)>, RegisterError> {
+    let mut span: Option<(u16, u16)> = None;
+    for register in registers {
+        let data = control_table.get(*register)?;
+        let end = data.address + data.length;
+        span = Some(match span {
+            Some((start, span_end)) => (start.min(data.address), span_end.max(end)),
+            None => (data.address, end),
+        });
+    }
+    Ok(span.map(|(start, end)| (start, end - start)))
+}
+
+/// Group spans shared by several servos into sync transactions, and the rest into bulk transactions.
+/// If putting every span in bulk transactions takes fewer transactions (e.g. each servo's span is shared with only
+/// one other), that is used instead. On a tie the sync transactions are kept, as their packets are shorter.
+fn group(
+    spans: Vec<ServoSpan>,
+    sync: impl Fn(u16, u16, Vec<u8>) -> Transaction,
+    bulk: impl Fn(Vec<ServoSpan>) -> Transaction,
+) -> Vec<Transaction> {
+    let mut by_span: BTreeMap<(u16, u16), Vec<u8>> = BTreeMap::new();
+    for span in spans {
+        by_span
+            .entry((span.address, span.length))
+            .or_default()
+            .push(span.id);
+    }
+    let span = |(address, length): (u16, u16), id: u8| ServoSpan {
+        id,
+        address,
+        length,
+    };
+
+    let mut transactions = Vec::new();
+    let mut rest = Vec::new();
+    for (&key, ids) in &by_span {
+        match ids.as_slice() {
+            [id] => rest.push(span(key, *id)),
+            _ => transactions.push(sync(key.0, key.1, ids.clone())),
+        }
+    }
+    let rest = batch(rest);
+    let all = batch(
+        by_span
+            .iter()
+            .flat_map(|(&key, ids)| ids.iter().map(move |id| span(key, *id))),
+    );
+
+    let to_transaction = |batch: Vec<ServoSpan>| match batch.as_slice() {
+        [span] => sync(span.address, span.length, vec![span.id]),
+        _ => bulk(batch),
+    };
+    if all.len() < transactions.len() + rest.len() {
+        return all.into_iter().map(to_transaction).collect();
+    }
+    transactions.extend(rest.into_iter().map(to_transaction));
+    transactions
+}
+
+/// Split spans into the fewest batches with at most one span per servo, as a bulk transaction can only have one span per servo
+fn batch(spans: impl IntoIterator<Item = ServoSpan>) -> Vec<Vec<ServoSpan>> {
+    let mut batches: Vec<Vec<ServoSpan>> = Vec::new();
+    for span in spans {
+        match batches
+            .iter_mut()
+            .find(|batch| batch.iter().all(|other| other.id != span.id))
+        {
+            Some(batch) => batch.push(span),
+            None => batches.push(vec![span]),
+        }
+    }
+    batches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Model, ModelGroup};
+
+    #[test]
+    fn test_plan_read() {
+        let servos = [
+            (1, ControlTable::new_with_model(Model::XM430_W350)),
+            (2, ControlTable::new_with_model(Model::XM430_W210)),
+            (3, ControlTable::new(ModelGroup::PH54)),
+            (4, ControlTable::new(ModelGroup::PH54)),
+            (5, ControlTable::new(ModelGroup::XL430)),
+        ];
+        let registers = [Register::PresentPosition, Register::GoalPosition];
+        let x_servos = [servos[0], servos[1], servos[4]];
+        assert_eq!(
+            plan_read(&x_servos, &registers).unwrap(),
+            [Transaction::SyncRead {
+                address: 116,
+                length: 20,
+                ids: vec![1, 2, 5]
+            }]
+        );
+        // one Bulk Read covers the X and P series, instead of a Sync Read of each
+        let plan = plan_read(&servos, &registers).unwrap();
+        assert_eq!(plan.len(), 1);
+        assert!(matches!(plan[0], Transaction::BulkRead { .. }));
+        assert_eq!(
+            plan[0]
+                .spans()
+                .iter()
+                .map(|span| (span.id, span.address))
+                .collect::<Vec<_>>(),
+            [(1, 116), (2, 116), (5, 116), (3, 564), (4, 564)]
+        );
+
+        // the XL430 has Present Load instead of Present Current
+        assert_eq!(
+            plan_read(&servos, &[Register::PresentCurrent]),
+            Err(RegisterError::Missing {
+                model_or_group: ModelGroup::XL430.into(),
+                register: Register::PresentCurrent
+            })
+        );
+    }
+
+    #[test]
+    fn test_plan_bulk_read() {
+        let servos = [
+            (1, ControlTable::new(ModelGroup::XM430)),
+            (2, ControlTable::new(ModelGroup::PH54)),
+            (3, ControlTable::new(ModelGroup::YM070)),
+        ];
+        let plan = plan_read(&servos, &[Register::PresentPosition]).unwrap();
+        assert_eq!(plan.len(), 1);
+        assert_eq!(
+            plan[0].spans(),
+            [
+                ServoSpan {
+                    id: 1,
+                    address: 132,
+                    length: 4
+                },
+                ServoSpan {
+                    id: 3,
+                    address: 552,
+                    length: 4
+                },
+                ServoSpan {
+                    id: 2,
+                    address: 580,
+                    length: 4
+                },
+            ]
+        );
+        let mut buffer = [0; 32];
+        let length = plan[0].read_packet(&mut buffer).unwrap();
+        assert_eq!(buffer[7], packet::Instruction::BulkRead as u8);
+        assert_eq!(length, 10 + 3 * 5);
+    }
+
+    #[test]
+    fn test_plan_write() {
+        let servos = [
+            (1, ControlTable::new(ModelGroup::XM430)),
+            (2, ControlTable::new(ModelGroup::XM430)),
+            (3, ControlTable::new(ModelGroup::PH54)),
+        ];
+        // Profile Acceleration, Profile Velocity and Goal Position are next to each other
+        let registers = [
+            Register::GoalPosition,
+            Register::ProfileVelocity,
+            Register::ProfileAcceleration,
+        ];
+        let span = |id, address, length| ServoSpan {
+            id,
+            address,
+            length,
+        };
+        assert_eq!(
+            plan_write(&servos[..2], &registers).unwrap(),
+            [Transaction::SyncWrite {
+                address: 108,
+                length: 12,
+                ids: vec![1, 2]
+            }]
+        );
+        assert_eq!(
+            plan_write(&servos, &registers).unwrap(),
+            [Transaction::BulkWrite {
+                writes: vec![span(1, 108, 12), span(2, 108, 12), span(3, 556, 12)]
+            }]
+        );
+
+        // Goal Velocity and Goal Position are not, so they are written separately,
+        // and a servo can only have one span in each Bulk Write
+        let plan = plan_write(
+            &servos[1..],
+            &[Register::GoalVelocity, Register::GoalPosition],
+        )
+        .unwrap();
+        assert_eq!(
+            plan,
+            [
+                Transaction::BulkWrite {
+                    writes: vec![span(2, 104, 4), span(3, 552, 4)]
+                },
+                Transaction::BulkWrite {
+                    writes: vec![span(2, 116, 4), span(3, 564, 4)]
+                },
+            ]
+        );
+
+        assert!(matches!(
+            plan_write(&servos, &[Register::PresentPosition]),
+            Err(RegisterError::ReadOnly { .. })
+        ));
+        assert_eq!(
+            plan[0].read_packet(&mut [0; 32]),
+            Err(PacketError::NoWriteData)
+        );
+    }
+
+    #[test]
+    fn test_plan_mixed_chain() {
+        let servos = [
+            (1, ControlTable::new_with_model(Model::XM430_W350)),
+            (2, ControlTable::new_with_model(Model::XL430_W250)),
+            (3, ControlTable::new_with_model(Model::PH54_200_S500_R)),
+        ];
+        let plan = plan_read(&servos, &[Register::PresentPosition]).unwrap();
+        assert_eq!(
+            plan,
+            [Transaction::BulkRead {
+                reads: vec![
+                    ServoSpan {
+                        id: 1,
+                        address: 132,
+                        length: 4
+                    },
+                    ServoSpan {
+                        id: 2,
+                        address: 132,
+                        length: 4
+                    },
+                    ServoSpan {
+                        id: 3,
+                        address: 580,
+                        length: 4
+                    },
+                ]
+            }]
+        );
+
+        // each servo has two spans, so bulk writes take as many transactions as sync writes
+        let plan = plan_write(
+            &servos[..2],
+            &[Register::GoalVelocity, Register::GoalPosition],
+        )
+        .unwrap();
+        assert_eq!(
+            plan,
+            [
+                Transaction::SyncWrite {
+                    address: 104,
+                    length: 4,
+                    ids: vec![1, 2]
+                },
+                Transaction::SyncWrite {
+                    address: 116,
+                    length: 4,
+                    ids: vec![1, 2]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_protocol_1() {
+        let servos = [
+            (1, ControlTable::new(ModelGroup::XM430)),
+            (2, ControlTable::new(ModelGroup::MX28)),
+        ];
+        let error = Err(RegisterError::UnsupportedProtocol {
+            model_or_group: ModelGroup::MX28.into(),
+            protocol: crate::Protocol::V1,
+        });
+        assert_eq!(plan_read(&servos, &[Register::Led]), error);
+        assert_eq!(plan_write(&servos, &[Register::Led]), error);
+    }
+}
@@ -0,0 +1,139 @@
+//! The values of the [`Register::BaudRate`](crate::Register::BaudRate) register.
+//!
+//! Protocol 2.0 models store an index into a table of bitrates, which differs between series.
+//! Protocol 1.0 models store a divisor of 2 Mbps, see [`ModelGroup::baud_rate`].
+
+use crate::models::ModelGroup;
+
+/// The bitrate is not supported by the model group
+#[derive(Debug, Clone, Copy, derive_more::Error, derive_more::Display, PartialEq)]
+#[display("{bps} bps is not supported by {model_group}")]
+pub struct UnsupportedBaudRate {
+    /// The model group
+    pub model_group: ModelGroup,
+    /// The bitrate requested
+    pub bps: u32,
+}
+
+/// The X and P series and the RH-P12-RN(A)
+const X_SERIES: &[u32] = &[
+    9_600, 57_600, 115_200, 1_000_000, 2_000_000, 3_000_000, 4_000_000, 4_500_000,
+];
+
+/// The Y series adds 6 Mbps and 10.5 Mbps
+const Y_SERIES: &[u32] = &[
+    9_600, 57_600, 115_200, 1_000_000, 2_000_000, 3_000_000, 4_000_000, 4_500_000, 6_000_000,
+    10_500_000,
+];
+
+/// The RH-P12-RN uses the table of the older PRO series, which starts at 2400 bps
+const PRO_SERIES: &[u32] = &[
+    2_400, 57_600, 115_200, 1_000_000, 2_000_000, 3_000_000, 4_000_000, 4_500_000, 10_500_000,
+];
+
+/// The MX series has extra high bitrates above the divisor values
+const MX_SERIES: &[(u8, u32)] = &[(250, 2_250_000), (251, 2_500_000), (252, 3_000_000)];
+
+/// Protocol 1.0 bitrates may differ from the bitrate of the controller by up to 3%
+const PROTOCOL_1_TOLERANCE: u32 = 3;
+
+/// How a model group maps the value of the Baud Rate register to a bitrate
+enum BaudRateTable {
+    /// The value is an index into the table
+    Index(&'static [u32]),
+    /// The bitrate is `2 Mbps / (value + 1)`, with the extra bitrates of the MX series
+    Divisor(&'static [(u8, u32)]),
+}
+
+impl BaudRateTable {
+    fn of(model_group: ModelGroup) -> Self {
+        use ModelGroup::*;
+        match model_group {
+            AX12 | AX18 | EX106 | RX10 | RX24 | RX28 | RX64 => BaudRateTable::Divisor(&[]),
+            MX106 | MX12 | MX28 | MX64 => BaudRateTable::Divisor(MX_SERIES),
+            XL320 => BaudRateTable::Index(&X_SERIES[..4]),
+            RHP12RN => BaudRateTable::Index(PRO_SERIES),
+            RHP12RNA => BaudRateTable::Index(&X_SERIES[..7]),
+            YM070 | YM080 => BaudRateTable::Index(Y_SERIES),
+            _ => BaudRateTable::Index(X_SERIES),
+        }
+    }
+}
+
+impl ModelGroup {
+    /// Convert the value of the Baud Rate register to a bitrate in bps.
+    /// Returns `None` if the value is not a valid Baud Rate of this model group.
+    ///
+    /// The bitrate of a protocol 1.0 divisor is rounded down, e.g. `34` is 57142 bps for a 57600 bps bus.
+    pub fn baud_rate(&self, value: u8) -> Option<u32> {
+        match BaudRateTable::of(*self) {
+            BaudRateTable::Index(table) => table.get(value as usize).copied(),
+            BaudRateTable::Divisor(extra) => match value {
+                0..=249 => Some(2_000_000 / (value as u32 + 1)),
+                _ => extra
+                    .iter()
+                    .find_map(|(v, bps)| (*v == value).then_some(*bps)),
+            },
+        }
+    }
+
+    /// Convert a bitrate in bps to the value of the Baud Rate register.
+    /// Returns [`UnsupportedBaudRate`] if the model group can't use the bitrate.
+    ///
+    /// A protocol 1.0 divisor is accepted if its bitrate is within 3% of `bps`.
+    pub fn baud_rate_value(&self, bps: u32) -> Result<u8, UnsupportedBaudRate> {
+        let value = match BaudRateTable::of(*self) {
+            BaudRateTable::Index(table) => table
+                .iter()
+                .position(|b| *b == bps)
+                .map(|index| index as u8),
+            BaudRateTable::Divisor(extra) => extra
+                .iter()
+                .find_map(|(value, b)| (*b == bps).then_some(*value))
+                .or_else(|| {
+                    let divisor = (2_000_000 + bps / 2).checked_div(bps)?;
+                    let value = u8::try_from(divisor.checked_sub(1)?).ok()?;
+                    let actual = self.baud_rate(value)?;
+                    (actual.abs_diff(bps) * 100 <= bps * PROTOCOL_1_TOLERANCE).then_some(value)
+                }),
+        };
+        value.ok_or(UnsupportedBaudRate {
+            model_group: *self,
+            bps,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_baud_rate() {
+        assert_eq!(ModelGroup::XM430.baud_rate(1), Some(57_600));
+        assert_eq!(ModelGroup::XM430.baud_rate(8), None);
+        assert_eq!(ModelGroup::XM430.baud_rate_value(4_000_000), Ok(6));
+        // the Y series has more bitrates
+        assert_eq!(ModelGroup::YM080.baud_rate_value(10_500_000), Ok(9));
+        assert_eq!(
+            ModelGroup::XM430.baud_rate_value(10_500_000),
+            Err(UnsupportedBaudRate {
+                model_group: ModelGroup::XM430,
+                bps: 10_500_000
+            })
+        );
+        assert_eq!(ModelGroup::RHP12RN.baud_rate(0), Some(2_400));
+        assert!(ModelGroup::XL320.baud_rate_value(2_000_000).is_err());
+    }
+
+    #[test]
+    fn test_protocol_1_baud_rate() {
+        assert_eq!(ModelGroup::AX12.baud_rate(1), Some(1_000_000));
+        assert_eq!(ModelGroup::AX12.baud_rate_value(57_600), Ok(34));
+        assert_eq!(ModelGroup::MX28.baud_rate_value(3_000_000), Ok(252));
+        assert_eq!(ModelGroup::MX28.baud_rate(252), Some(3_000_000));
+        assert_eq!(ModelGroup::AX12.baud_rate(252), None);
+        assert!(ModelGroup::AX12.baud_rate_value(3_000_000).is_err());
+        assert!(ModelGroup::AX12.baud_rate_value(0).is_err());
+    }
+}
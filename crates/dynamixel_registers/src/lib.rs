@@ -1,5 +1,12 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod baud_rate;
 pub mod models;
+pub mod operating_mode;
 mod registers;
-pub use registers::{Register, RegisterData};
+pub mod unit;
+pub mod value;
+pub use operating_mode::OperatingMode;
+pub use registers::{Access, Area, Register, RegisterData};
+pub use unit::{Quantity, Unit};
+pub use value::{Bound, Value, ValueError, ValueRange, ValueType};
@@ -1,8 +1,10 @@
+use crate::{OperatingMode, Register};
 use derive_more::From;
 use num_derive::{FromPrimitive, ToPrimitive};
 use num_traits::{FromPrimitive, ToPrimitive};
 #[cfg(feature = "serde")]
 use std::str::FromStr;
+use strum::IntoEnumIterator;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, derive_more::Display, Ord, PartialOrd, From)]
 #[repr(u16)]
@@ -37,21 +39,38 @@ impl ModelOrModelGroup {
 )]
 #[non_exhaustive]
 pub enum ModelGroup {
+    _2XC430,
+    _2XL430,
+    AX12,
+    AX18,
+    EX106,
+    MX106,
+    MX12,
+    MX28,
+    MX64,
     PH42,
     PH54,
     PM42,
     PM54,
+    RHP12RN,
+    RHP12RNA,
+    RX10,
+    RX24,
+    RX28,
+    RX64,
     XC330,
     XC430,
     XD430,
     XD540,
     XH430,
     XH540,
+    XL320,
     XL330,
     XL430,
     XM335,
     XM430,
     XM540,
+    XW430,
     XW540,
     YM070,
     YM080,
@@ -61,6 +80,92 @@ impl ModelGroup {
     pub fn contains(&self, model: Model) -> bool {
         self == &model.model_group()
     }
+
+    /// Iterate over the models of this model group
+    pub fn models(&self) -> impl Iterator<Item = Model> {
+        let model_group = *self;
+        Model::iter().filter(move |model| model_group.contains(*model))
+    }
+
+    /// The number of axes of a servo of this model group.
+    /// Each axis of a dual-axis servo (e.g. 2XL430) has its own ID and its own copy of the control table.
+    pub fn axes(&self) -> u8 {
+        match self {
+            ModelGroup::_2XC430 | ModelGroup::_2XL430 => 2,
+            _ => 1,
+        }
+    }
+
+    /// Check if this model group is a gripper (e.g. RH-P12-RN) rather than a servo driving a horn.
+    /// [`Register::GoalPosition`] of a gripper sets how far it is closed.
+    pub fn is_gripper(&self) -> bool {
+        matches!(self, ModelGroup::RHP12RN | ModelGroup::RHP12RNA)
+    }
+
+    /// The registers shared by both axes of a dual-axis servo, so writing them through one ID changes the other.
+    /// Empty for a single-axis servo.
+    pub fn shared_registers(&self) -> &'static [Register] {
+        match self {
+            ModelGroup::_2XC430 | ModelGroup::_2XL430 => {
+                &[Register::BaudRate, Register::ProtocolType]
+            }
+            _ => &[],
+        }
+    }
+
+    /// The operating modes supported by this model group, see [`Register::OperatingMode`].
+    /// Empty if the model group has no Operating Mode register.
+    pub fn operating_modes(&self) -> &'static [OperatingMode] {
+        use ModelGroup::*;
+        use OperatingMode::*;
+        match self {
+            // no current sensor
+            _2XC430 | _2XL430 | XC430 | XL430 => &[Velocity, Position, ExtendedPosition, Pwm],
+            XC330 | XD430 | XD540 | XH430 | XH540 | XL330 | XM335 | XM430 | XM540 | XW430
+            | XW540 => &[
+                Current,
+                Velocity,
+                Position,
+                ExtendedPosition,
+                CurrentBasedPosition,
+                Pwm,
+            ],
+            PH42 | PH54 | PM42 | PM54 => &[Current, Velocity, Position, ExtendedPosition, Pwm],
+            // the Y series has no modes of its own: it uses the X series values (0 ~ 5), without PWM
+            YM070 | YM080 => &[
+                Current,
+                Velocity,
+                Position,
+                ExtendedPosition,
+                CurrentBasedPosition,
+            ],
+            RHP12RN | RHP12RNA => &[Current, CurrentBasedPosition],
+            _ => &[],
+        }
+    }
+
+    /// The version of the protocol the control table of this model group is defined for
+    pub fn protocol(&self) -> Protocol {
+        use ModelGroup::*;
+        match self {
+            AX12 | AX18 | EX106 | MX106 | MX12 | MX28 | MX64 | RX10 | RX24 | RX28 | RX64 => {
+                Protocol::V1
+            }
+            _ => Protocol::V2,
+        }
+    }
+}
+
+/// The version of the Dynamixel communication protocol
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, derive_more::Display, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Protocol {
+    /// Protocol 1.0, used by the AX, RX, EX and MX series
+    #[display("1.0")]
+    V1,
+    /// Protocol 2.0
+    #[display("2.0")]
+    V2,
 }
 
 impl From<Model> for ModelGroup {
@@ -71,7 +176,16 @@ impl From<Model> for ModelGroup {
 
 /// Dynamixel model names and numbers
 #[derive(
-    PartialEq, Eq, Clone, Copy, FromPrimitive, ToPrimitive, derive_more::Display, Ord, PartialOrd,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    FromPrimitive,
+    ToPrimitive,
+    derive_more::Display,
+    Ord,
+    PartialOrd,
+    strum::EnumIter,
 )]
 #[repr(u16)]
 #[allow(non_camel_case_types)]
@@ -79,6 +193,24 @@ impl From<Model> for ModelGroup {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, strum::EnumString))]
 #[non_exhaustive]
 pub enum Model {
+    AX_12A = 12,
+    AX_12W = 300,
+    AX_18A = 18,
+
+    RX_10 = 10,
+    RX_24F = 24,
+    RX_28 = 28,
+    RX_64 = 64,
+
+    EX_106 = 107,
+
+    MX_12W = 360,
+    MX_28 = 29,
+    MX_64 = 310,
+    MX_106 = 320,
+
+    XL320 = 350,
+
     XL330_M077 = 1190,
     XL330_M288 = 1200,
 
@@ -93,6 +225,9 @@ pub enum Model {
 
     XL430_W250 = 1060,
 
+    _2XL430_W250 = 1090,
+    _2XC430_W250 = 1160,
+
     XM430_W210 = 1030,
     XM430_W350 = 1020,
 
@@ -115,6 +250,9 @@ pub enum Model {
     XD540_T150 = 1111,
     XD540_T270 = 1101,
 
+    XW430_T200 = 1280,
+    XW430_T333 = 1270,
+
     XW540_T140 = 1180,
     XW540_T260 = 1170,
 
@@ -129,6 +267,9 @@ pub enum Model {
     PM54_040_S250_R = 2110,
     PM54_060_S250_R = 2120,
 
+    RH_P12_RN = 35073,
+    RH_P12_RNA = 35074,
+
     YM070_210_M001_RH = 4000,
     YM070_210_B001_RH = 4010,
     YM070_200_R051_RH = 4020,
@@ -148,13 +289,28 @@ impl Model {
     pub fn model_group(&self) -> ModelGroup {
         use Model::*;
         match self {
+            AX_12A | AX_12W => ModelGroup::AX12,
+            AX_18A => ModelGroup::AX18,
+            RX_10 => ModelGroup::RX10,
+            RX_24F => ModelGroup::RX24,
+            RX_28 => ModelGroup::RX28,
+            RX_64 => ModelGroup::RX64,
+            EX_106 => ModelGroup::EX106,
+            MX_12W => ModelGroup::MX12,
+            MX_28 => ModelGroup::MX28,
+            MX_64 => ModelGroup::MX64,
+            MX_106 => ModelGroup::MX106,
             XD540_T270 | XD540_T150 => ModelGroup::XD540,
             XH540_W270 | XH540_W150 | XH540_V270 | XH540_V150 => ModelGroup::XH540,
             XM540_W270 | XM540_W150 => ModelGroup::XM540,
             XC430_W150 | XC430_W240 => ModelGroup::XC430,
             XL430_W250 => ModelGroup::XL430,
+            _2XL430_W250 => ModelGroup::_2XL430,
+            _2XC430_W250 => ModelGroup::_2XC430,
+            XW430_T200 | XW430_T333 => ModelGroup::XW430,
             XW540_T260 | XW540_T140 | XW540_H260 => ModelGroup::XW540,
             XC330_T181 | XC330_T288 | XC330_M181 | XC330_M288 => ModelGroup::XC330,
+            XL320 => ModelGroup::XL320,
             XL330_M077 | XL330_M288 => ModelGroup::XL330,
             XD430_T350 | XD430_T210 => ModelGroup::XD430,
             XH430_W350 | XH430_W210 | XH430_V350 | XH430_V210 => ModelGroup::XH430,
@@ -168,8 +324,15 @@ impl Model {
             PH54_100_S500_R | PH54_200_S500_R => ModelGroup::PH54,
             PM42_010_S260_R => ModelGroup::PM42,
             PM54_040_S250_R | PM54_060_S250_R => ModelGroup::PM54,
+            RH_P12_RN => ModelGroup::RHP12RN,
+            RH_P12_RNA => ModelGroup::RHP12RNA,
         }
     }
+
+    /// The version of the protocol the control table of this model is defined for
+    pub fn protocol(&self) -> Protocol {
+        self.model_group().protocol()
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -228,7 +391,8 @@ impl PartialEq<u16> for Model {
 
 #[cfg(test)]
 mod tests {
-    use super::{Model, UnknownModel};
+    use super::{Model, ModelGroup, Protocol, UnknownModel};
+    use crate::OperatingMode;
 
     #[test]
     fn test_model_from_number() {
@@ -237,6 +401,55 @@ mod tests {
 
         let model: Result<Model, UnknownModel> = 1075.try_into();
         assert_eq!(model, Err(UnknownModel(1075)));
+
+        let model: Result<Model, UnknownModel> = 350.try_into();
+        assert_eq!(model, Ok(Model::XL320));
+
+        let model: Result<Model, UnknownModel> = 1270.try_into();
+        assert_eq!(model, Ok(Model::XW430_T333));
+
+        let model: Result<Model, UnknownModel> = 12.try_into();
+        assert_eq!(model, Ok(Model::AX_12A));
+
+        let model: Result<Model, UnknownModel> = 35074.try_into();
+        assert_eq!(model, Ok(Model::RH_P12_RNA));
+    }
+
+    #[test]
+    fn test_operating_modes() {
+        assert!(!ModelGroup::XL430
+            .operating_modes()
+            .contains(&OperatingMode::Current));
+        assert!(ModelGroup::XM430
+            .operating_modes()
+            .contains(&OperatingMode::Current));
+        assert!(ModelGroup::MX28.operating_modes().is_empty());
+
+        // the Y series uses the same values as the X series
+        let y_modes = ModelGroup::YM080.operating_modes();
+        assert!(y_modes
+            .iter()
+            .all(|mode| ModelGroup::XM430.operating_modes().contains(mode)));
+        assert!(!y_modes.contains(&OperatingMode::Pwm));
+        assert_eq!(u8::from(OperatingMode::Position), 3);
+    }
+
+    #[test]
+    fn test_dual_axis() {
+        let model: Result<Model, UnknownModel> = 1090.try_into();
+        assert_eq!(model, Ok(Model::_2XL430_W250));
+        assert_eq!(Model::_2XC430_W250.model_group().axes(), 2);
+        assert_eq!(ModelGroup::XL430.axes(), 1);
+        assert!(ModelGroup::XL430.shared_registers().is_empty());
+        assert!(!ModelGroup::_2XL430.is_gripper());
+        assert!(ModelGroup::RHP12RNA.is_gripper());
+    }
+
+    #[test]
+    fn test_protocol() {
+        assert_eq!(Model::MX_28.protocol(), Protocol::V1);
+        assert_eq!(Model::XM430_W350.protocol(), Protocol::V2);
+        assert_eq!(Protocol::V1.to_string(), "1.0");
     }
 
     #[test]
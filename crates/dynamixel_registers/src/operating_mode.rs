@@ -0,0 +1,96 @@
+//! The values of the [`Register::OperatingMode`] register.
+
+use crate::{Register, Value};
+
+/// The control mode set by the [`Register::OperatingMode`] register.
+/// Not every model supports every mode, see [`ModelGroup::operating_modes`](crate::models::ModelGroup::operating_modes).
+/// The values are the same on the X, P and Y series; the Y series has no modes of its own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum OperatingMode {
+    /// Controls the current (torque) with [`Register::GoalCurrent`]
+    #[display("Current Control Mode")]
+    Current = 0,
+    /// Controls the velocity with [`Register::GoalVelocity`]
+    #[display("Velocity Control Mode")]
+    Velocity = 1,
+    /// Controls the position within one turn (or the position limits) with [`Register::GoalPosition`]
+    #[display("Position Control Mode")]
+    Position = 3,
+    /// Controls the position over multiple turns with [`Register::GoalPosition`]
+    #[display("Extended Position Control Mode")]
+    ExtendedPosition = 4,
+    /// Controls the position over multiple turns with [`Register::GoalPosition`], limiting the current to [`Register::GoalCurrent`]
+    #[display("Current-based Position Control Mode")]
+    CurrentBasedPosition = 5,
+    /// Controls the PWM output (voltage) with [`Register::GoalPwm`]
+    #[display("PWM Control Mode")]
+    Pwm = 16,
+}
+
+impl OperatingMode {
+    /// The goal register the mode drives
+    pub const fn goal_register(&self) -> Register {
+        match self {
+            OperatingMode::Current => Register::GoalCurrent,
+            OperatingMode::Velocity => Register::GoalVelocity,
+            OperatingMode::Position
+            | OperatingMode::ExtendedPosition
+            | OperatingMode::CurrentBasedPosition => Register::GoalPosition,
+            OperatingMode::Pwm => Register::GoalPwm,
+        }
+    }
+}
+
+/// The value of the Operating Mode register is not a known mode
+#[derive(Debug, Clone, Copy, derive_more::Error, derive_more::Display, PartialEq)]
+#[display("{_0} is not a known operating mode")]
+pub struct UnknownOperatingMode(#[error(not(source))] pub u8);
+
+impl TryFrom<u8> for OperatingMode {
+    type Error = UnknownOperatingMode;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(OperatingMode::Current),
+            1 => Ok(OperatingMode::Velocity),
+            3 => Ok(OperatingMode::Position),
+            4 => Ok(OperatingMode::ExtendedPosition),
+            5 => Ok(OperatingMode::CurrentBasedPosition),
+            16 => Ok(OperatingMode::Pwm),
+            value => Err(UnknownOperatingMode(value)),
+        }
+    }
+}
+
+impl From<OperatingMode> for u8 {
+    fn from(mode: OperatingMode) -> Self {
+        mode as u8
+    }
+}
+
+impl From<OperatingMode> for Value {
+    fn from(mode: OperatingMode) -> Self {
+        Value::U8(mode as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operating_mode() {
+        assert_eq!(
+            OperatingMode::try_from(4),
+            Ok(OperatingMode::ExtendedPosition)
+        );
+        assert_eq!(OperatingMode::try_from(2), Err(UnknownOperatingMode(2)));
+        assert_eq!(Value::from(OperatingMode::Pwm), Value::U8(16));
+        assert_eq!(
+            OperatingMode::CurrentBasedPosition.goal_register(),
+            Register::GoalPosition
+        );
+    }
+}
@@ -1,17 +1,84 @@
 //! This module defines the RegisterData struct and each possible register in the control table.
 
-/// RegisterData is used to store the address and length of register.
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+use crate::value::{Value, ValueError, ValueType};
+
+/// RegisterData is used to store the address, length, value type, access level and memory area of register.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RegisterData {
     /// The address of the register
     pub address: u16,
     /// The number of bytes in the register
     pub length: u16,
+    /// The type of the value stored in the register
+    pub value_type: ValueType,
+    /// Whether the register can be written to or only read
+    pub access: Access,
+    /// The memory area the register is stored in
+    pub area: Area,
+}
+
+impl RegisterData {
+    /// Returns `true` if the register can be written to.
+    pub const fn is_writable(&self) -> bool {
+        matches!(self.access, Access::ReadWrite)
+    }
+
+    /// Returns `true` if the register is stored in the EEPROM area.
+    /// EEPROM registers can only be written while torque is disabled.
+    pub const fn is_eeprom(&self) -> bool {
+        matches!(self.area, Area::Eeprom)
+    }
+
+    /// Decode the little-endian bytes read from this register into a typed [`Value`].
+    /// Returns [`ValueError::LengthMismatch`] if `bytes` is not the length of the register.
+    pub fn decode(&self, bytes: &[u8]) -> Result<Value, ValueError> {
+        self.value_type.decode(bytes)
+    }
+
+    /// Encode a typed [`Value`] into the little-endian bytes to write to this register.
+    /// Returns [`ValueError::TypeMismatch`] if `value` is not the value type of the register
+    /// or [`ValueError::LengthMismatch`] if `buffer` is not the length of the register.
+    pub fn encode(&self, value: impl Into<Value>, buffer: &mut [u8]) -> Result<(), ValueError> {
+        let value = value.into();
+        if value.value_type() != self.value_type {
+            return Err(ValueError::TypeMismatch {
+                expected: self.value_type,
+                actual: value.value_type(),
+            });
+        }
+        value.encode(buffer)
+    }
+}
+
+/// The access level of a register, as listed in the `Access` column of the control table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Access {
+    /// The register can only be read (`R`)
+    #[display("R")]
+    Read,
+    /// The register can be read and written (`RW`)
+    #[display("RW")]
+    ReadWrite,
+}
+
+/// The memory area of a register, as listed in the `Area` column of the control table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Area {
+    /// Non-volatile memory, retained after power off. Locked while torque is enabled.
+    #[display("EEPROM")]
+    Eeprom,
+    /// Volatile memory, reset to the initial value on power on
+    #[display("RAM")]
+    Ram,
 }
 
 #[allow(missing_docs)]
-#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash, Ord, PartialOrd, derive_more::Display)]
+#[derive(
+    Clone, Copy, Eq, PartialEq, Debug, Hash, Ord, PartialOrd, derive_more::Display, strum::EnumCount,
+)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize, strum::EnumString)
@@ -124,5 +191,41 @@ pub enum Register {
     InverterTemperatureLimit,
     PositionLimitThreshold,
     PwmSlope,
+    CwAngleLimit,
+    CcwAngleLimit,
+    MaxTorque,
+    AlarmLed,
+    MultiTurnOffset,
+    ResolutionDivider,
+    CwComplianceMargin,
+    CcwComplianceMargin,
+    CwComplianceSlope,
+    CcwComplianceSlope,
+    DGain,
+    IGain,
+    PGain,
+    MovingSpeed,
+    TorqueLimit,
+    PresentSpeed,
+    PresentVoltage,
+    Registered,
+    Lock,
+    Punch,
+    SensedCurrent,
+    Current,
+    TorqueControlModeEnable,
+    GoalTorque,
+    GoalAcceleration,
+    ControlMode,
     PresentLoad,
 }
+
+impl Register {
+    /// The number of registers, used to size tables indexed by [`Register::index`]
+    pub const COUNT: usize = <Register as strum::EnumCount>::COUNT;
+
+    /// The index of this register in a table of [`Register::COUNT`] entries
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+}
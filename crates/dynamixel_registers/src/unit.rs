@@ -0,0 +1,79 @@
+//! This module defines the physical unit of the value stored in each register.
+//!
+//! Raw register values are converted to SI units (rad, rad/s, A, V, °C, s) by multiplying with the scale of the [`Unit`].
+
+/// The physical quantity a register value represents, and the SI unit it is converted to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Quantity {
+    /// Angle in radians
+    #[display("rad")]
+    Angle,
+    /// Angular velocity in radians per second
+    #[display("rad/s")]
+    AngularVelocity,
+    /// Angular acceleration in radians per second squared
+    #[display("rad/s²")]
+    AngularAcceleration,
+    /// Electric current in amperes
+    #[display("A")]
+    Current,
+    /// Voltage in volts
+    #[display("V")]
+    Voltage,
+    /// Temperature in degrees Celsius
+    #[display("°C")]
+    Temperature,
+    /// Time in seconds
+    #[display("s")]
+    Time,
+    /// A ratio of the maximum, e.g. the PWM duty cycle or load, where `1.0` is 100%
+    #[display("ratio")]
+    Ratio,
+}
+
+/// The unit of a register value: the [`Quantity`] and the size of one raw step in SI units.
+#[derive(Clone, Copy, Debug, PartialEq, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[display("{scale} {quantity}")]
+pub struct Unit {
+    /// The physical quantity of the register value
+    pub quantity: Quantity,
+    /// The size of one raw step in SI units
+    pub scale: f32,
+}
+
+impl Unit {
+    /// Convert a raw register value to SI units
+    pub fn to_si(&self, raw: i64) -> f32 {
+        raw as f32 * self.scale
+    }
+
+    /// Convert a value in SI units to the nearest raw register value
+    pub fn from_si(&self, value: f32) -> i64 {
+        let raw = value / self.scale;
+        // `f32::round` is not available in `no_std`
+        if raw < 0.0 {
+            (raw - 0.5) as i64
+        } else {
+            (raw + 0.5) as i64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Quantity, Unit};
+
+    #[test]
+    fn test_conversion() {
+        let unit = Unit {
+            quantity: Quantity::Current,
+            scale: 0.00269,
+        };
+        assert!((unit.to_si(1000) - 2.69).abs() < 1e-4);
+        assert_eq!(unit.from_si(2.69), 1000);
+        assert_eq!(unit.from_si(-2.69), -1000);
+        assert_eq!(unit.to_string(), "0.00269 A");
+    }
+}
@@ -0,0 +1,283 @@
+//! This module defines the type of the value stored in each register, how it is encoded on the bus
+//! and the range of valid values.
+//!
+//! All values are encoded little-endian.
+
+use crate::Register;
+
+/// The type of the value stored in a register.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ValueType {
+    /// Unsigned 1 byte value
+    #[display("u8")]
+    U8,
+    /// Unsigned 2 byte value
+    #[display("u16")]
+    U16,
+    /// Unsigned 4 byte value
+    #[display("u32")]
+    U32,
+    /// Signed 2 byte value
+    #[display("i16")]
+    I16,
+    /// Signed 4 byte value
+    #[display("i32")]
+    I32,
+}
+
+impl ValueType {
+    /// The number of bytes used to store this type
+    pub const fn length(&self) -> u16 {
+        match self {
+            ValueType::U8 => 1,
+            ValueType::U16 | ValueType::I16 => 2,
+            ValueType::U32 | ValueType::I32 => 4,
+        }
+    }
+
+    /// Returns `true` if this type is signed
+    pub const fn is_signed(&self) -> bool {
+        matches!(self, ValueType::I16 | ValueType::I32)
+    }
+
+    /// The smallest value this type can hold
+    pub const fn min_value(&self) -> i64 {
+        match self {
+            ValueType::U8 | ValueType::U16 | ValueType::U32 => 0,
+            ValueType::I16 => i16::MIN as i64,
+            ValueType::I32 => i32::MIN as i64,
+        }
+    }
+
+    /// The largest value this type can hold
+    pub const fn max_value(&self) -> i64 {
+        match self {
+            ValueType::U8 => u8::MAX as i64,
+            ValueType::U16 => u16::MAX as i64,
+            ValueType::U32 => u32::MAX as i64,
+            ValueType::I16 => i16::MAX as i64,
+            ValueType::I32 => i32::MAX as i64,
+        }
+    }
+
+    /// Convert an `i64` to a [`Value`] of this type.
+    /// Returns `None` if the value does not fit in this type.
+    pub fn from_i64(&self, value: i64) -> Option<Value> {
+        let value = match self {
+            ValueType::U8 => Value::U8(value.try_into().ok()?),
+            ValueType::U16 => Value::U16(value.try_into().ok()?),
+            ValueType::U32 => Value::U32(value.try_into().ok()?),
+            ValueType::I16 => Value::I16(value.try_into().ok()?),
+            ValueType::I32 => Value::I32(value.try_into().ok()?),
+        };
+        Some(value)
+    }
+
+    /// Decode a little-endian value of this type from `bytes`.
+    /// Returns [`ValueError::LengthMismatch`] if `bytes` is not exactly [`ValueType::length`] long.
+    pub fn decode(&self, bytes: &[u8]) -> Result<Value, ValueError> {
+        let length_mismatch = || ValueError::LengthMismatch {
+            expected: self.length(),
+            actual: bytes.len(),
+        };
+        let value = match self {
+            ValueType::U8 => Value::U8(u8::from_le_bytes(
+                bytes.try_into().map_err(|_| length_mismatch())?,
+            )),
+            ValueType::U16 => Value::U16(u16::from_le_bytes(
+                bytes.try_into().map_err(|_| length_mismatch())?,
+            )),
+            ValueType::U32 => Value::U32(u32::from_le_bytes(
+                bytes.try_into().map_err(|_| length_mismatch())?,
+            )),
+            ValueType::I16 => Value::I16(i16::from_le_bytes(
+                bytes.try_into().map_err(|_| length_mismatch())?,
+            )),
+            ValueType::I32 => Value::I32(i32::from_le_bytes(
+                bytes.try_into().map_err(|_| length_mismatch())?,
+            )),
+        };
+        Ok(value)
+    }
+}
+
+/// A typed value read from or written to a register.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, derive_more::Display, derive_more::From)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(missing_docs)]
+pub enum Value {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    I16(i16),
+    I32(i32),
+}
+
+impl Value {
+    /// The [`ValueType`] of this value
+    pub const fn value_type(&self) -> ValueType {
+        match self {
+            Value::U8(_) => ValueType::U8,
+            Value::U16(_) => ValueType::U16,
+            Value::U32(_) => ValueType::U32,
+            Value::I16(_) => ValueType::I16,
+            Value::I32(_) => ValueType::I32,
+        }
+    }
+
+    /// The value widened to an `i64`, which can hold every [`ValueType`] without loss.
+    pub const fn as_i64(&self) -> i64 {
+        match *self {
+            Value::U8(v) => v as i64,
+            Value::U16(v) => v as i64,
+            Value::U32(v) => v as i64,
+            Value::I16(v) => v as i64,
+            Value::I32(v) => v as i64,
+        }
+    }
+
+    /// Encode this value little-endian into `buffer`.
+    /// Returns [`ValueError::LengthMismatch`] if `buffer` is not exactly [`ValueType::length`] long.
+    pub fn encode(&self, buffer: &mut [u8]) -> Result<(), ValueError> {
+        let expected = self.value_type().length();
+        if buffer.len() != expected as usize {
+            return Err(ValueError::LengthMismatch {
+                expected,
+                actual: buffer.len(),
+            });
+        }
+        match self {
+            Value::U8(v) => buffer.copy_from_slice(&v.to_le_bytes()),
+            Value::U16(v) => buffer.copy_from_slice(&v.to_le_bytes()),
+            Value::U32(v) => buffer.copy_from_slice(&v.to_le_bytes()),
+            Value::I16(v) => buffer.copy_from_slice(&v.to_le_bytes()),
+            Value::I32(v) => buffer.copy_from_slice(&v.to_le_bytes()),
+        }
+        Ok(())
+    }
+}
+
+impl From<Value> for i64 {
+    fn from(value: Value) -> Self {
+        value.as_i64()
+    }
+}
+
+/// One end of a [`ValueRange`], as listed in the `Range` column of the control table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Bound {
+    /// A fixed value, e.g. `4095`
+    #[display("{_0}")]
+    Value(i64),
+    /// The value of another register, e.g. `Max Position Limit(48)`
+    #[display("{_0}")]
+    Register(Register),
+    /// The negated value of another register, e.g. `-Current Limit(38)`
+    #[display("-{_0}")]
+    NegRegister(Register),
+}
+
+impl Bound {
+    /// Resolve the bound to a value, using `register_value` to look up the value of a register.
+    /// Returns `None` if the bound refers to a register and `register_value` returns `None`.
+    pub fn resolve(&self, register_value: impl FnOnce(Register) -> Option<i64>) -> Option<i64> {
+        match *self {
+            Bound::Value(value) => Some(value),
+            Bound::Register(register) => register_value(register),
+            Bound::NegRegister(register) => register_value(register).map(|value| -value),
+        }
+    }
+}
+
+/// The inclusive range of valid values of a register.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[display("{min} ~ {max}")]
+pub struct ValueRange {
+    /// The smallest valid value
+    pub min: Bound,
+    /// The largest valid value
+    pub max: Bound,
+}
+
+/// An error encoding or decoding a [`Value`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+pub enum ValueError {
+    /// The number of bytes does not match the length of the value type
+    #[display("expected {expected} bytes but got {actual}")]
+    LengthMismatch {
+        /// The length of the value type
+        expected: u16,
+        /// The number of bytes provided
+        actual: usize,
+    },
+    /// The value does not have the same type as the register
+    #[display("expected a {expected} value but got {actual}")]
+    TypeMismatch {
+        /// The value type of the register
+        expected: ValueType,
+        /// The type of the value provided
+        actual: ValueType,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bound, Value, ValueError, ValueRange, ValueType};
+    use crate::Register;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(
+            ValueType::I32.decode(&(-1234i32).to_le_bytes()),
+            Ok(Value::I32(-1234))
+        );
+        assert_eq!(ValueType::U16.decode(&[0x06, 0x04]), Ok(Value::U16(1030)));
+        assert_eq!(
+            ValueType::U16.decode(&[0x06]),
+            Err(ValueError::LengthMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_encode() {
+        let mut buffer = [0; 4];
+        Value::I32(-1234).encode(&mut buffer).unwrap();
+        assert_eq!(buffer, (-1234i32).to_le_bytes());
+        assert_eq!(
+            Value::I16(-1).encode(&mut buffer),
+            Err(ValueError::LengthMismatch {
+                expected: 2,
+                actual: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_i64() {
+        assert_eq!(ValueType::I16.from_i64(-885), Some(Value::I16(-885)));
+        assert_eq!(ValueType::U8.from_i64(256), None);
+        assert_eq!(ValueType::U32.from_i64(-1), None);
+    }
+
+    #[test]
+    fn test_range_display() {
+        let range = ValueRange {
+            min: Bound::NegRegister(Register::CurrentLimit),
+            max: Bound::Register(Register::CurrentLimit),
+        };
+        assert_eq!(range.to_string(), "-CurrentLimit ~ CurrentLimit");
+        assert_eq!(range.min.resolve(|_| Some(1193)), Some(-1193));
+
+        let range = ValueRange {
+            min: Bound::Value(-1044479),
+            max: Bound::Value(1044479),
+        };
+        assert_eq!(range.to_string(), "-1044479 ~ 1044479");
+    }
+}
@@ -1,4 +1,4 @@
-use crate::parse::ModelGroup;
+use crate::parse::{ModelGroup, ModelValues};
 use itertools::Itertools;
 use std::fs;
 use std::fs::File;
@@ -31,15 +31,15 @@ pub fn create_match(mod_path: &PathBuf, all_models: Vec<ModelGroup>) -> anyhow::
         .open(mod_path)?;
 
     writeln!(mod_file)?;
-    writeln!(mod_file, r#"#[cfg(feature = "std")]"#)?;
-    writeln!(mod_file, "pub(crate) fn control_table_from_model(model: &dynamixel_registers::models::Model) -> &'static std::collections::HashMap<dynamixel_registers::Register, dynamixel_registers::RegisterData> {{")?;
+    writeln!(mod_file, "pub(crate) fn control_table_from_model(model: &dynamixel_registers::models::Model) -> &'static crate::model_macro::RegisterTable {{")?;
     writeln!(mod_file, "    use dynamixel_registers::models::Model::*;")?;
     writeln!(mod_file, "    match model {{")?;
     for group in &all_models {
         for (alias, models) in group.alias() {
             writeln!(
                 mod_file,
-                "        {} => {}::table(),",
+                // `self::` as a model can have the same name as its model group (e.g. XL320)
+                "        {} => self::{}::table(),",
                 models.iter().map(|m| m.to_string()).join(" | "),
                 alias,
             )?;
@@ -49,8 +49,7 @@ pub fn create_match(mod_path: &PathBuf, all_models: Vec<ModelGroup>) -> anyhow::
     writeln!(mod_file, "    }}")?;
     writeln!(mod_file, "}}")?;
     writeln!(mod_file)?;
-    writeln!(mod_file, r#"#[cfg(feature = "std")]"#)?;
-    writeln!(mod_file, "pub(crate) fn control_table_from_model_group(model_group: &dynamixel_registers::models::ModelGroup) -> &'static std::collections::HashMap<dynamixel_registers::Register, dynamixel_registers::RegisterData> {{")?;
+    writeln!(mod_file, "pub(crate) fn control_table_from_model_group(model_group: &dynamixel_registers::models::ModelGroup) -> &'static crate::model_macro::RegisterTable {{")?;
     writeln!(mod_file, "    use dynamixel_registers::models::ModelGroup;")?;
     writeln!(mod_file, "    match model_group {{")?;
     for group in &all_models {
@@ -65,6 +64,54 @@ pub fn create_match(mod_path: &PathBuf, all_models: Vec<ModelGroup>) -> anyhow::
     writeln!(mod_file, r#"        _ => panic!("unknown model group")"#)?;
     writeln!(mod_file, "    }}")?;
     writeln!(mod_file, "}}")?;
+    writeln!(mod_file)?;
+    writeln!(mod_file, "pub(crate) fn range_from_model_or_group(model_or_group: dynamixel_registers::models::ModelOrModelGroup, register: dynamixel_registers::Register) -> Option<dynamixel_registers::ValueRange> {{")?;
+    writeln!(mod_file, "    use dynamixel_registers::models::ModelGroup;")?;
+    writeln!(mod_file, "    match model_or_group.model_group() {{")?;
+    for group in &all_models {
+        for alias in group.alias().keys() {
+            writeln!(
+                mod_file,
+                "        ModelGroup::{} => {}::range_of(model_or_group, register),",
+                alias, alias,
+            )?;
+        }
+    }
+    writeln!(mod_file, r#"        _ => panic!("unknown model group")"#)?;
+    writeln!(mod_file, "    }}")?;
+    writeln!(mod_file, "}}")?;
+    writeln!(mod_file)?;
+    writeln!(mod_file, "pub(crate) fn unit_from_model_or_group(model_or_group: dynamixel_registers::models::ModelOrModelGroup, register: dynamixel_registers::Register) -> Option<dynamixel_registers::Unit> {{")?;
+    writeln!(mod_file, "    use dynamixel_registers::models::ModelGroup;")?;
+    writeln!(mod_file, "    match model_or_group.model_group() {{")?;
+    for group in &all_models {
+        for alias in group.alias().keys() {
+            writeln!(
+                mod_file,
+                "        ModelGroup::{} => {}::unit_of(model_or_group, register),",
+                alias, alias,
+            )?;
+        }
+    }
+    writeln!(mod_file, r#"        _ => panic!("unknown model group")"#)?;
+    writeln!(mod_file, "    }}")?;
+    writeln!(mod_file, "}}")?;
+    writeln!(mod_file)?;
+    writeln!(mod_file, "pub(crate) fn initial_value_from_model_or_group(model_or_group: dynamixel_registers::models::ModelOrModelGroup, register: dynamixel_registers::Register) -> Option<dynamixel_registers::Value> {{")?;
+    writeln!(mod_file, "    use dynamixel_registers::models::ModelGroup;")?;
+    writeln!(mod_file, "    match model_or_group.model_group() {{")?;
+    for group in &all_models {
+        for alias in group.alias().keys() {
+            writeln!(
+                mod_file,
+                "        ModelGroup::{} => {}::initial_value_of(model_or_group, register),",
+                alias, alias,
+            )?;
+        }
+    }
+    writeln!(mod_file, r#"        _ => panic!("unknown model group")"#)?;
+    writeln!(mod_file, "    }}")?;
+    writeln!(mod_file, "}}")?;
     Ok(())
 }
 
@@ -86,14 +133,77 @@ fn to_model_macro_from_group(file: &mut File, model_group: &ModelGroup) -> anyho
     )?;
 
     for row in model_group.table().values() {
+        let range = match model_group.model_values(row.data_name, |r| r.range) {
+            ModelValues::Shared(Some(range)) => format!(" ({})", range),
+            ModelValues::Shared(None) => String::new(),
+            ModelValues::PerModel(ranges) => format!(
+                " {{{}}}",
+                ranges
+                    .iter()
+                    .filter_map(
+                        |(model, range)| range.map(|range| format!("{}: ({})", model, range))
+                    )
+                    .join(", ")
+            ),
+        };
+        let unit = match model_group.model_values(row.data_name, |r| r.unit) {
+            ModelValues::Shared(Some(unit)) => format!(" {:?}({})", unit.quantity, unit.scale),
+            ModelValues::Shared(None) => String::new(),
+            ModelValues::PerModel(units) => format!(
+                " <{}>",
+                units
+                    .iter()
+                    .filter_map(|(model, unit)| unit
+                        .map(|unit| format!("{}: {:?}({})", model, unit.quantity, unit.scale)))
+                    .join(", ")
+            ),
+        };
+        let initial_value = match model_group.model_values(row.data_name, |r| r.initial_value) {
+            ModelValues::Shared(Some(initial_value)) => format!(" = {}", initial_value),
+            ModelValues::Shared(None) => String::new(),
+            ModelValues::PerModel(initial_values) => format!(
+                " [{}]",
+                initial_values
+                    .iter()
+                    .filter_map(|(model, initial_value)| initial_value
+                        .map(|initial_value| format!("{} = {}", model, initial_value)))
+                    .join(", ")
+            ),
+        };
+        // the macro only takes a firmware version shared by every model
+        let min_firmware = match model_group.model_values(row.data_name, |r| r.min_firmware) {
+            ModelValues::Shared(Some(version)) => format!(" @ {}", version),
+            _ => String::new(),
+        };
         writeln!(
             file,
-            "    {}: {}, {},",
-            row.data_name, row.address, row.size,
+            "    {}: {}, {}, {:?}, {:?}, {:?}{}{}{}{},",
+            row.data_name,
+            row.address,
+            row.size,
+            row.value_type,
+            row.access,
+            row.area,
+            unit,
+            range,
+            initial_value,
+            min_firmware,
         )?;
     }
 
-    writeln!(file, "}}];")?;
+    if model_group.indirect().is_empty() {
+        writeln!(file, "}}];")?;
+    } else {
+        writeln!(
+            file,
+            "}} indirect [{}]];",
+            model_group
+                .indirect()
+                .iter()
+                .map(|block| format!("({}, {}, {})", block.address, block.data, block.count))
+                .join(", ")
+        )?;
+    }
     Ok(())
 }
 
@@ -18,9 +18,23 @@ fn main() -> Result<()> {
         "emanual/docs/en/dxl/x",
         "emanual/docs/en/dxl/y",
         "emanual/docs/en/dxl/p",
+        "emanual/docs/en/dxl/ax",
+        "emanual/docs/en/dxl/rx",
+        "emanual/docs/en/dxl/ex",
+        "emanual/docs/en/dxl/mx",
     ];
 
-    let all_model_files: Vec<_> = dirs.iter().flat_map(collect_model_files).try_collect()?;
+    // the grippers and hands are documented with the other platforms, so only take their files
+    let platform_files = collect_model_files("emanual/docs/en/platform").filter_ok(|f| {
+        let name = f.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+        name.starts_with("rh_") && name.ends_with(".md")
+    });
+
+    let all_model_files: Vec<_> = dirs
+        .iter()
+        .flat_map(collect_model_files)
+        .chain(platform_files)
+        .try_collect()?;
 
     let models: Vec<_> = all_model_files
         .iter()
@@ -38,10 +52,10 @@ fn main() -> Result<()> {
             println!("comparing {} with {}", mg.name(), m.model);
             mg.table() == &m.table
         }) {
-            mg.insert_model(m.model);
+            mg.insert_model(m);
         } else {
-            let mut mg = ModelGroup::new(m.table);
-            mg.insert_model(m.model);
+            let mut mg = ModelGroup::new(m.table.clone(), m.indirect.clone());
+            mg.insert_model(m);
             all_models.push(mg);
         }
     }
@@ -78,7 +92,8 @@ fn main() -> Result<()> {
 
 fn filter_files(path: impl AsRef<Path>) -> bool {
     let path = path.as_ref().to_str().unwrap();
-    let filter = ["test", "xl320", "2x", "xw430", "x.md", "dxl_p.md", "y.md"];
+    // `-2.md` are the protocol 2.0 firmware of the MX series
+    let filter = ["test", "x.md", "dxl_p.md", "y.md", "-2.md"];
     filter.iter().any(|f| path.contains(f)).not()
 }
 
@@ -2,12 +2,14 @@ use anyhow::{anyhow, Context};
 use convert_case::{Case, Casing};
 use dynamixel_registers::models::Model as DModel;
 use dynamixel_registers::models::ModelGroup as DModelGroup;
-use dynamixel_registers::Register;
+use dynamixel_registers::models::Protocol;
+use dynamixel_registers::{Access, Area, Bound, Quantity, Register, Unit, ValueRange, ValueType};
 use itertools::Itertools;
 use num_traits::FromPrimitive;
 use regex::Regex;
 use std::cmp::Ordering;
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::BTreeMap;
+use std::f64::consts::{PI, TAU};
 use std::fs;
 use std::ops::Not;
 use std::path::Path;
@@ -15,20 +17,33 @@ use std::str::FromStr;
 
 #[derive(Debug, Clone, Default)]
 pub struct ModelGroup {
-    model: BTreeSet<DModel>,
+    models: BTreeMap<DModel, BTreeMap<Register, ControlTableRow>>,
     table: BTreeMap<Register, ControlTableRow>,
+    indirect: Vec<IndirectBlock>,
+}
+
+/// A value of a register that may differ between the models of a [`ModelGroup`]
+pub(crate) enum ModelValues<T> {
+    /// Every model has the same value
+    Shared(T),
+    /// The value of each model
+    PerModel(BTreeMap<DModel, T>),
 }
 
 impl ModelGroup {
-    pub(crate) fn new(table: BTreeMap<Register, ControlTableRow>) -> Self {
+    pub(crate) fn new(
+        table: BTreeMap<Register, ControlTableRow>,
+        indirect: Vec<IndirectBlock>,
+    ) -> Self {
         Self {
             table,
+            indirect,
             ..Default::default()
         }
     }
 
-    pub(crate) fn insert_model(&mut self, model: DModel) {
-        self.model.insert(model);
+    pub(crate) fn insert_model(&mut self, model: Model) {
+        self.models.insert(model.model, model.table);
     }
 
     pub(crate) fn name(&self) -> String {
@@ -38,45 +53,81 @@ impl ModelGroup {
     pub(crate) fn table(&self) -> &BTreeMap<Register, ControlTableRow> {
         &self.table
     }
+
+    pub(crate) fn indirect(&self) -> &[IndirectBlock] {
+        &self.indirect
+    }
     pub(crate) fn table_name(&self) -> String {
         self.name().to_uppercase()
     }
 
     pub(crate) fn file_name(&self) -> String {
-        self.name().to_lowercase()
+        // model groups starting with a digit (e.g. _2XL430) would otherwise join with a double underscore
+        self.name().to_lowercase().replace("__", "_")
     }
 
     pub(crate) fn alias(&self) -> BTreeMap<DModelGroup, Vec<DModel>> {
-        self.model.iter().fold(BTreeMap::new(), |mut acc, model| {
+        self.models.keys().fold(BTreeMap::new(), |mut acc, model| {
             let alias = model.model_group();
             acc.entry(alias).or_default().push(*model);
             acc
         })
     }
+
+    /// Collect a value of a register from every model in the group
+    pub(crate) fn model_values<T: PartialEq>(
+        &self,
+        register: Register,
+        value: impl Fn(&ControlTableRow) -> T,
+    ) -> ModelValues<T> {
+        let values: BTreeMap<DModel, T> = self
+            .models
+            .iter()
+            .filter_map(|(model, table)| Some((*model, value(table.get(&register)?))))
+            .collect();
+        let first = values.values().next();
+        if values.values().all(|v| Some(v) == first) {
+            ModelValues::Shared(
+                values
+                    .into_values()
+                    .next()
+                    .expect("a model group has models"),
+            )
+        } else {
+            ModelValues::PerModel(values)
+        }
+    }
 }
 
-#[derive(Debug, Clone, Eq)]
-#[expect(dead_code)]
+#[derive(Debug, Clone)]
 pub(crate) struct ControlTableRow {
     pub(crate) address: u16,
     pub(crate) size: u16,
+    pub(crate) value_type: ValueType,
     pub(crate) data_name: Register,
-    access: String,
-    initial_value: Option<i32>,
-    range: String,
-    unit: String,
-    area: String,
+    pub(crate) access: Access,
+    pub(crate) initial_value: Option<i32>,
+    pub(crate) range: Option<ValueRange>,
+    raw_unit: String,
+    /// Resolved once the model is known, as `pulse` units depend on the resolution of the model
+    pub(crate) unit: Option<Unit>,
+    pub(crate) area: Area,
+    /// The firmware version the register was added in, from the description of the register
+    pub(crate) min_firmware: Option<u8>,
 }
 
 impl PartialEq for ControlTableRow {
     fn eq(&self, other: &Self) -> bool {
         self.address == other.address
             && self.size == other.size
+            && self.value_type == other.value_type
             && self.data_name == other.data_name
             && self.access == other.access
     }
 }
 
+impl Eq for ControlTableRow {}
+
 impl Ord for ControlTableRow {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.address.cmp(&other.address)
@@ -85,7 +136,7 @@ impl Ord for ControlTableRow {
 
 impl PartialOrd for ControlTableRow {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.address.cmp(&other.address))
+        Some(self.cmp(other))
     }
 }
 
@@ -123,13 +174,26 @@ impl ControlTableRow {
         let address = find("address").unwrap();
         let size = find("size").unwrap();
         let data_name = find("data").unwrap();
-        let access = find("access").unwrap();
+        let access = match find("access").unwrap().as_str() {
+            "R" => Access::Read,
+            "RW" | "R/W" => Access::ReadWrite,
+            access => return Err(anyhow!("failed to parse access {}", access)),
+        };
         let initial_value = handle_double_model(find("initial").unwrap());
-        let range = find("range").unwrap().replace("<br>", " ").replace(",", "");
-        let unit = find("unit").unwrap();
+        // some protocol 1.0 tables have no range or unit columns
+        let range = find("range")
+            .unwrap_or_default()
+            .replace("<br>", " ")
+            .replace(",", "");
+        let unit = find("unit").unwrap_or_default();
         let area = find("area")
             .or_else(|| area.map(|s| s.to_string()))
             .ok_or(anyhow!("missing area"))?;
+        let area = match area.to_uppercase().as_str() {
+            "EEPROM" => Area::Eeprom,
+            "RAM" => Area::Ram,
+            area => return Err(anyhow!("failed to parse area {}", area)),
+        };
 
         let mut data_name = Regex::new(r"\[(.+)]")
             .unwrap()
@@ -152,6 +216,12 @@ impl ControlTableRow {
                 return Ok(None);
             }
         };
+        let size = size
+            .parse()
+            .with_context(|| anyhow!("failed to parse size {}", size))?;
+        let range = parse_range(&range);
+        // the model is not known yet, see `parse_table` for the models without signed feedback
+        let value_type = value_type(size, is_signed(data_name, range, true))?;
         let initial_value = (initial_value.is_empty()
             || initial_value.contains("-")
             || initial_value.contains("br"))
@@ -168,23 +238,253 @@ impl ControlTableRow {
             address: address
                 .parse()
                 .with_context(|| anyhow!("failed to parse address {}", address))?,
-            size: size
-                .parse()
-                .with_context(|| anyhow!("failed to parse size {}", size))?,
+            size,
+            value_type,
             data_name,
             access,
             initial_value,
             range,
-            unit,
+            raw_unit: unit,
+            unit: None,
             area,
+            min_firmware: None,
         }))
     }
 }
 
+/// Registers holding a signed quantity that the control tables list without a range (`-`),
+/// or with a range between registers that doesn't show the sign
+const SIGNED_REGISTERS: &[Register] = &[
+    Register::GoalPosition,
+    Register::PresentPosition,
+    Register::PresentVelocity,
+    Register::PresentCurrent,
+    Register::PresentPwm,
+    Register::PresentLoad,
+    Register::VelocityTrajectory,
+    Register::PositionTrajectory,
+];
+
+/// The control table doesn't list the type of a register, so a register is signed if its range
+/// has a negative lower bound, or if it is one of the [`SIGNED_REGISTERS`] on a model with `signed_feedback`.
+/// A register without a range (`-`) is unsigned otherwise.
+fn is_signed(register: Register, range: Option<ValueRange>, signed_feedback: bool) -> bool {
+    let negative = match range.map(|range| range.min) {
+        Some(Bound::Value(min)) => min < 0,
+        Some(Bound::NegRegister(_)) => true,
+        Some(Bound::Register(_)) | None => false,
+    };
+    negative || (signed_feedback && SIGNED_REGISTERS.contains(&register))
+}
+
+/// The protocol 1.0 servos and the XL-320 store positions as unsigned values, and Present Speed and
+/// Present Load as a magnitude with a direction bit, so none of the [`SIGNED_REGISTERS`] are signed
+fn has_signed_feedback(model: DModel) -> bool {
+    model.protocol() == Protocol::V2 && model.model_group() != DModelGroup::XL320
+}
+
+fn value_type(size: u16, signed: bool) -> anyhow::Result<ValueType> {
+    let value_type = match (size, signed) {
+        (1, false) => ValueType::U8,
+        (2, false) => ValueType::U16,
+        (4, false) => ValueType::U32,
+        (2, true) => ValueType::I16,
+        (4, true) => ValueType::I32,
+        (size, signed) => {
+            return Err(anyhow!(
+                "unsupported register size {} (signed: {})",
+                size,
+                signed
+            ))
+        }
+    };
+    Ok(value_type)
+}
+
+/// Parse a range like `0 ~ 4095` or `-Current Limit(38) ~ Current Limit(38)`.
+/// Returns `None` if the register has no range (`-`) or the range cannot be parsed.
+fn parse_range(range: &str) -> Option<ValueRange> {
+    let (min, max) = range.split_once("~")?;
+    let range = ValueRange {
+        min: parse_bound(min)?,
+        max: parse_bound(max)?,
+    };
+    Some(range)
+}
+
+fn parse_bound(bound: &str) -> Option<Bound> {
+    let bound = bound.trim();
+    if let Ok(value) = bound.parse() {
+        return Some(Bound::Value(value));
+    }
+    let (negative, register) = match bound.strip_prefix("-") {
+        Some(register) => (true, register),
+        None => (false, bound),
+    };
+    let register = Regex::new(r"\(.*\)").expect("tested").replace(register, "");
+    let register: Register = match register.trim().to_case(Case::Pascal).parse() {
+        Ok(register) => register,
+        Err(e) => {
+            println!("error parsing range bound {}: {}", bound, e);
+            return None;
+        }
+    };
+    if negative {
+        Some(Bound::NegRegister(register))
+    } else {
+        Some(Bound::Register(register))
+    }
+}
+
+/// Parse a unit like `0.229 [rev/min]` or `2.69 [mA]` into SI units.
+/// `pulse` units are converted to radians with `pulses_per_revolution`.
+/// Returns `None` if the register has no unit (`-`) or the unit is not a physical quantity.
+fn parse_unit(unit: &str, pulses_per_revolution: Option<u32>) -> Option<Unit> {
+    let unit = Regex::new(r"</?sup>")
+        .expect("tested")
+        .replace_all(unit, "")
+        .replace(",", "");
+    let captures = Regex::new(r"^\s*([\d.]+)\s*\[(.+)]")
+        .expect("tested")
+        .captures(&unit)?;
+    let value: f64 = captures.get(1)?.as_str().parse().ok()?;
+    let (quantity, scale) = match captures.get(2)?.as_str().trim() {
+        "rev/min" | "RPM" => (Quantity::AngularVelocity, TAU / 60.0),
+        "rev/min2" | "rev/min²" => (Quantity::AngularAcceleration, TAU / 3600.0),
+        "°" | "deg" => (Quantity::Angle, PI / 180.0),
+        "pulse" => (Quantity::Angle, TAU / f64::from(pulses_per_revolution?)),
+        "mA" => (Quantity::Current, 0.001),
+        "A" => (Quantity::Current, 1.0),
+        "V" => (Quantity::Voltage, 1.0),
+        "°C" => (Quantity::Temperature, 1.0),
+        "s" | "sec" => (Quantity::Time, 1.0),
+        "ms" | "msec" => (Quantity::Time, 0.001),
+        "μs" | "μsec" | "us" | "usec" => (Quantity::Time, 0.000001),
+        "%" => (Quantity::Ratio, 0.01),
+        unit => {
+            println!("unknown unit {}", unit);
+            return None;
+        }
+    };
+    // keep 6 significant digits, the control tables aren't more precise than that
+    let scale = format!("{:.5e}", value * scale).parse().ok()?;
+    Some(Unit { quantity, scale })
+}
+
+/// The number of position pulses per output revolution, used to convert `pulse` units.
+/// The control tables list it in the description of the Present Position register.
+fn pulses_per_revolution(model: DModel) -> Option<u32> {
+    use DModel::*;
+    let pulses = match model {
+        PH42_020_S300_R => 607500,
+        PH54_100_S500_R | PH54_200_S500_R => 1003846,
+        PM42_010_S260_R => 526374,
+        PM54_040_S250_R | PM54_060_S250_R => 502834,
+        YM070_210_M001_RH | YM070_210_B001_RH | YM080_230_M001_RH | YM080_230_B001_RH => 524288,
+        YM070_200_R051_RH | YM070_210_A051_RH | YM080_230_R051_RH | YM080_230_A051_RH => {
+            524288 * 51
+        }
+        YM070_200_R099_RH | YM070_200_A099_RH | YM080_230_R099_RH | YM080_230_A099_RH => {
+            524288 * 99
+        }
+        model if model.model_group().to_string().starts_with('X') => 4096,
+        _ => return None,
+    };
+    Some(pulses)
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct Model {
     pub(crate) model: dynamixel_registers::models::Model,
     pub(crate) table: BTreeMap<Register, ControlTableRow>,
+    pub(crate) indirect: Vec<IndirectBlock>,
+}
+
+/// A block of Indirect Address registers and the Indirect Data registers they map to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct IndirectBlock {
+    /// The address of the first Indirect Address register
+    pub(crate) address: u16,
+    /// The address of the first Indirect Data register
+    pub(crate) data: u16,
+    pub(crate) count: u16,
+}
+
+/// Parse the Indirect blocks from the "Indirect Address N" and "Indirect Data N" rows.
+/// The tables only list the first and last rows of a block (the rest are "…"), or a single row
+/// for the whole block (e.g. `| 256 ~ 510 | 2 | [Indirect Address 1 ~ 128] |`),
+/// so a block is a run of numbers whose addresses are evenly spaced from the first.
+fn parse_indirect(file: &str) -> Vec<IndirectBlock> {
+    let re = Regex::new(
+        r"^\|\s*([\d,]+)(?:\s*~\s*([\d,]+))?\s*\|.*\[Indirect (Address|Data) (\d+)(?:\s*~\s*(\d+))?]",
+    )
+    .expect("tested");
+    let number =
+        |capture: Option<regex::Match>| capture?.as_str().replace(",", "").parse::<u16>().ok();
+    let mut addresses = BTreeMap::new();
+    let mut data = BTreeMap::new();
+    for captures in file.lines().filter_map(|line| re.captures(line.trim())) {
+        let (Some(first_address), Some(first)) = (number(captures.get(1)), number(captures.get(4)))
+        else {
+            continue;
+        };
+        let rows = match (number(captures.get(2)), number(captures.get(5))) {
+            (Some(last_address), Some(last)) => vec![(first, first_address), (last, last_address)],
+            _ => vec![(first, first_address)],
+        };
+        let registers = match &captures[3] {
+            "Address" => &mut addresses,
+            _ => &mut data,
+        };
+        registers.extend(rows);
+    }
+
+    let mut blocks: Vec<(u16, IndirectBlock)> = Vec::new();
+    for (number, address) in addresses {
+        let Some(data) = data.get(&number).copied() else {
+            continue;
+        };
+        match blocks.last_mut() {
+            Some((first, block))
+                if address == block.address + (number - *first) * 2
+                    && data == block.data + (number - *first) =>
+            {
+                block.count = number - *first + 1;
+            }
+            _ => blocks.push((
+                number,
+                IndirectBlock {
+                    address,
+                    data,
+                    count: 1,
+                },
+            )),
+        }
+    }
+    blocks.into_iter().map(|(_, block)| block).collect()
+}
+
+/// Parse the firmware version each register was added in.
+/// The description of a register starts with a heading like `### <a name="bus-watchdog"></a>**[Bus Watchdog(98)]**`,
+/// and mentions the version in a note like `This feature is available from firmware v38`.
+fn parse_min_firmware(file: &str) -> BTreeMap<Register, u8> {
+    let heading = Regex::new(r"^#{2,4}\s.*\*\*\[([^(\]]+)").expect("tested");
+    let firmware = Regex::new(r"(?i)firmware\s*v(\d+)").expect("tested");
+    let mut versions = BTreeMap::new();
+    let mut register = None;
+    for line in file.lines() {
+        if let Some(captures) = heading.captures(line) {
+            register = captures[1].trim().to_case(Case::Pascal).parse().ok();
+            continue;
+        }
+        let (Some(register), Some(captures)) = (register, firmware.captures(line)) else {
+            continue;
+        };
+        if let Ok(version) = captures[1].parse() {
+            versions.entry(register).or_insert(version);
+        }
+    }
+    versions
 }
 
 pub fn parse_table(model_file: impl AsRef<Path>) -> anyhow::Result<Model> {
@@ -260,7 +560,99 @@ pub fn parse_table(model_file: impl AsRef<Path>) -> anyhow::Result<Model> {
         DModel::from_u16(model_number)
             .ok_or_else(|| anyhow!("cannot find model for {} = {},", name, model_number))?,
     );
-    let model = Model { model, table };
+    let pulses_per_revolution = pulses_per_revolution(model);
+    let min_firmware = parse_min_firmware(&file);
+    let signed_feedback = has_signed_feedback(model);
+    let table = table
+        .into_iter()
+        .map(|(register, mut row)| {
+            row.unit = parse_unit(&row.raw_unit, pulses_per_revolution);
+            row.min_firmware = min_firmware.get(&register).copied();
+            if !signed_feedback {
+                row.value_type = value_type(row.size, is_signed(register, row.range, false))?;
+            }
+            Ok((register, row))
+        })
+        .collect::<anyhow::Result<_>>()?;
+    let indirect = parse_indirect(&file);
+    let model = Model {
+        model,
+        table,
+        indirect,
+    };
 
     Ok(model)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_indirect() {
+        let file = "\
+| 168 | 2 | [Indirect Address 1](#indirect-address) | RW | 224 | 64 ~ 661 | - |
+| ... | ... | ... | ... | ... | ... | ... |
+| 206 | 2 | [Indirect Address 20](#indirect-address) | RW | 262 | 64 ~ 661 | - |
+| 224 | 1 | [Indirect Data 1](#indirect-data) | RW | 0 | 0 ~ 255 | - |
+| 243 | 1 | [Indirect Data 20](#indirect-data) | RW | 0 | 0 ~ 255 | - |
+| 256 ~ 510 | 2 | [Indirect Address 21 ~ 148](#indirect-address) | RW | - | - | - |
+| 634 ~ 761 | 1 | [Indirect Data 21 ~ 148](#indirect-data) | RW | 0 | 0 ~ 255 | - |
+";
+        assert_eq!(
+            parse_indirect(file),
+            [
+                IndirectBlock {
+                    address: 168,
+                    data: 224,
+                    count: 20
+                },
+                IndirectBlock {
+                    address: 256,
+                    data: 634,
+                    count: 128
+                },
+            ]
+        );
+    }
+
+    const HEADER: &str =
+        "| Address | Size<br>(Byte) | Data Name | Access | Initial Value | Range | Unit |";
+
+    fn parse_row(row: &str) -> ControlTableRow {
+        ControlTableRow::parse(HEADER, row, Some("RAM"))
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_value_type() {
+        // registers without a range are unsigned, whatever their size
+        let row = parse_row(
+            "| 146 | 1 | [Present Temperature](#present-temperature) | R | - | - | 1 [°C] |",
+        );
+        assert_eq!(row.value_type, ValueType::U8);
+        let row = parse_row("| 0 | 2 | [Model Number](#model-number) | R | 1,060 | - | - |");
+        assert_eq!(row.value_type, ValueType::U16);
+        let row = parse_row(
+            "| 144 | 2 | [Present Input Voltage](#present-input-voltage) | R | - | - | 0.1 [V] |",
+        );
+        assert_eq!(row.value_type, ValueType::U16);
+
+        let row = parse_row(
+            "| 132 | 4 | [Present Position](#present-position) | R | - | - | 1 [pulse] |",
+        );
+        assert_eq!(row.value_type, ValueType::I32);
+        let row = parse_row("| 104 | 4 | [Goal Velocity](#goal-velocity) | RW | - | -Velocity Limit(44) ~ Velocity Limit(44) | 0.229 [rev/min] |");
+        assert_eq!(row.value_type, ValueType::I32);
+        let row = parse_row("| 20 | 4 | [Homing Offset](#homing-offset) | RW | 0 | -1,044,479 ~ 1,044,479 | 1 [pulse] |");
+        assert_eq!(row.value_type, ValueType::I32);
+
+        // Present Load is a magnitude with a direction bit on the protocol 1.0 servos and the XL-320
+        assert!(is_signed(Register::PresentLoad, None, true));
+        assert!(!is_signed(Register::PresentLoad, None, false));
+        assert!(has_signed_feedback(DModel::XM430_W350));
+        assert!(!has_signed_feedback(DModel::MX_28));
+        assert!(!has_signed_feedback(DModel::XL320));
+    }
+}